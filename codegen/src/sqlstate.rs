@@ -16,6 +16,35 @@ struct Error {
     message: Option<String>,
 }
 
+impl Error {
+    fn variant(&self) -> String {
+        camel_case(&self.name)
+    }
+}
+
+struct Section {
+    class: String,
+    title: String,
+}
+
+impl Section {
+    fn predicate(&self) -> String {
+        let title = match self.title.find(" (") {
+            Some(end) => &self.title[..end],
+            None => &self.title,
+        };
+
+        let name = title
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|x| !x.is_empty())
+            .map(|x| x.to_lowercase())
+            .collect::<Vec<_>>()
+            .join("_");
+
+        format!("is_{name}")
+    }
+}
+
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -50,18 +79,30 @@ const ERRCODES_TXT: &str = include_str!("errcodes.txt");
 pub fn build(filename: &str) -> std::io::Result<()> {
     let mut file = BufWriter::new(File::create(filename)?);
 
-    let errors = parse_errors();
+    let (errors, sections) = parse_errors();
 
     make_header(&mut file)?;
     make_consts(&errors, &mut file)?;
-    make_type(&errors, &mut file)
+    make_type(&errors, &sections, &mut file)?;
+    make_enum(&errors, &mut file)
 }
 
-fn parse_errors() -> BTreeMap<String, Error> {
+fn parse_errors() -> (BTreeMap<String, Error>, Vec<Section>) {
     let mut errors = BTreeMap::new();
+    let mut sections = Vec::new();
 
     for line in ERRCODES_TXT.lines() {
-        if line.starts_with('#') || line.starts_with("Section") || line.trim().is_empty() {
+        if let Some(section) = line.strip_prefix("Section: Class ") {
+            let (class, title) = section.split_once(" - ").unwrap();
+
+            sections.push(Section {
+                class: class.to_string(),
+                title: title.to_string(),
+            });
+            continue;
+        }
+
+        if line.starts_with('#') || line.trim().is_empty() {
             continue;
         }
 
@@ -86,34 +127,117 @@ fn parse_errors() -> BTreeMap<String, Error> {
         errors.insert(code, error);
     }
 
-    errors
+    (errors, sections)
+}
+
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|x| {
+            let mut chars = x.chars();
+
+            match chars.next() {
+                Some(first) => first.to_string() + &chars.as_str().to_lowercase(),
+                None => String::new(),
+            }
+        })
+        .collect()
 }
 
 fn make_header(file: &mut BufWriter<File>) -> std::io::Result<()> {
     writeln!(file, "// Autogenerated file - DO NOT EDIT")
 }
 
-fn make_type(errors: &BTreeMap<String, Error>, file: &mut BufWriter<File>) -> std::io::Result<()> {
+fn make_type(
+    errors: &BTreeMap<String, Error>,
+    sections: &[Section],
+    file: &mut BufWriter<File>,
+) -> std::io::Result<()> {
     let mut from_code = Vec::new();
 
     for (id, error) in errors {
-        from_code.push(format!("            \"{id}\" => {},", error.name));
+        from_code.push(format!("            \"{id}\" => Some({}),", error.name));
+    }
+
+    let mut classes = Vec::new();
+
+    for section in sections {
+        classes.push(format!(
+            "
+    /// Class {class} - {title}
+    pub fn {predicate}(&self) -> bool {{
+        self.code.starts_with(\"{class}\")
+    }}",
+            class = section.class,
+            title = section.title,
+            predicate = section.predicate(),
+        ));
     }
 
     write!(
         file,
         "
 impl State {{
-    /// Creates a `State` from its error code.
-    pub fn from_code(s: &str) -> State {{
+    /// Creates a `State` from its error code, returns `None` for an unknown code.
+    pub fn try_from_code(s: &str) -> Option<State> {{
         match s {{
 {}
-            _ => unreachable!(),
+            _ => None,
+        }}
+    }}
+{}
+}}
+",
+        from_code.join("\n"),
+        classes.join("\n"),
+    )
+}
+
+fn make_enum(errors: &BTreeMap<String, Error>, file: &mut BufWriter<File>) -> std::io::Result<()> {
+    let mut variants = Vec::new();
+    let mut from_code = Vec::new();
+    let mut states = Vec::new();
+
+    for (id, error) in errors {
+        let variant = error.variant();
+
+        if let Some(message) = &error.message {
+            variants.push(format!("    /// {message}"));
+        }
+        variants.push(format!("    {variant},"));
+        from_code.push(format!("            \"{id}\" => Some(Self::{variant}),"));
+        states.push(format!("            Self::{variant} => &{},", error.name));
+    }
+
+    write!(
+        file,
+        "
+/// A SQLSTATE error code, as an enum.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SqlState {{
+{}
+}}
+
+impl SqlState {{
+    /// Creates a `SqlState` from its error code, returns `None` for an unknown code.
+    pub fn from_code(s: &str) -> Option<SqlState> {{
+        match s {{
+{}
+            _ => None,
+        }}
+    }}
+
+    /// Returns the `State` of this error code.
+    pub fn state(&self) -> &'static State {{
+        match self {{
+{}
         }}
     }}
 }}
 ",
-        from_code.join("\n")
+        variants.join("\n"),
+        from_code.join("\n"),
+        states.join("\n"),
     )
 }
 
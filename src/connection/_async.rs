@@ -36,9 +36,26 @@ impl Connection {
         param_formats: &[crate::Format],
         result_format: crate::Format,
     ) -> crate::errors::Result {
-        let (values, formats, lengths) = Self::transform_params(param_values, param_formats);
+        let params = Self::transform_params(param_types, param_values, param_formats);
 
-        Self::trace_query("Sending", command, param_types, param_values, param_formats);
+        self.send_query_with(command, &params, result_format)
+    }
+
+    /**
+     * Same as `libpq::Connection::send_query_params`, with owned parameters.
+     *
+     * See
+     * [PQsendQueryParams](https://www.postgresql.org/docs/current/libpq-async.html#LIBPQ-PQSENDQUERYPARAMS).
+     */
+    pub fn send_query_with(
+        &self,
+        command: &str,
+        params: &[Param],
+        result_format: crate::Format,
+    ) -> crate::errors::Result {
+        let raw = RawParams::new(params)?;
+
+        Self::trace_query("Sending", command, &[], params);
 
         let c_command = crate::ffi::to_cstr(command);
 
@@ -46,23 +63,11 @@ impl Connection {
             pq_sys::PQsendQueryParams(
                 self.into(),
                 c_command.as_ptr(),
-                values.len() as i32,
-                if param_types.is_empty() {
-                    std::ptr::null()
-                } else {
-                    param_types.as_ptr()
-                },
-                values.as_ptr(),
-                if lengths.is_empty() {
-                    std::ptr::null()
-                } else {
-                    lengths.as_ptr()
-                },
-                if formats.is_empty() {
-                    std::ptr::null()
-                } else {
-                    formats.as_ptr()
-                },
+                raw.len(),
+                raw.types(),
+                raw.values(),
+                raw.lengths(),
+                raw.formats(),
                 result_format as i32,
             )
         };
@@ -88,7 +93,7 @@ impl Connection {
         param_types: &[crate::Oid],
     ) -> crate::errors::Result {
         let prefix = format!("Sending prepare {}", name.unwrap_or("anonymous"));
-        Self::trace_query(&prefix, query, param_types, &[], &[]);
+        Self::trace_query(&prefix, query, param_types, &[]);
 
         let c_name = crate::ffi::to_cstr(name.unwrap_or_default());
         let c_query = crate::ffi::to_cstr(query);
@@ -122,10 +127,26 @@ impl Connection {
         param_formats: &[crate::Format],
         result_format: crate::Format,
     ) -> crate::errors::Result {
-        let prefix = format!("Send {} prepared query", name.unwrap_or("anonymous"));
-        Self::trace_query(&prefix, "", &[], param_values, param_formats);
+        let params = Self::transform_params(&[], param_values, param_formats);
 
-        let (values, formats, lengths) = Self::transform_params(param_values, param_formats);
+        self.send_query_prepared_with(name, &params, result_format)
+    }
+
+    /**
+     * Same as `libpq::Connection::send_query_prepared`, with owned parameters.
+     *
+     * See [PQsendQueryPrepared](https://www.postgresql.org/docs/current/libpq-async.html#LIBPQ-PQSENDQUERYPREPARED).
+     */
+    pub fn send_query_prepared_with(
+        &self,
+        name: Option<&str>,
+        params: &[Param],
+        result_format: crate::Format,
+    ) -> crate::errors::Result {
+        let raw = RawParams::new(params)?;
+
+        let prefix = format!("Send {} prepared query", name.unwrap_or("anonymous"));
+        Self::trace_query(&prefix, "", &[], params);
 
         let c_name = crate::ffi::to_cstr(name.unwrap_or_default());
 
@@ -133,18 +154,10 @@ impl Connection {
             pq_sys::PQsendQueryPrepared(
                 self.into(),
                 c_name.as_ptr(),
-                values.len() as i32,
-                values.as_ptr(),
-                if lengths.is_empty() {
-                    std::ptr::null()
-                } else {
-                    lengths.as_ptr()
-                },
-                if formats.is_empty() {
-                    std::ptr::null()
-                } else {
-                    formats.as_ptr()
-                },
+                raw.len(),
+                raw.values(),
+                raw.lengths(),
+                raw.formats(),
                 result_format as i32,
             )
         };
@@ -198,6 +211,67 @@ impl Connection {
         }
     }
 
+    /**
+     * Submits a request to close the specified prepared statement, without waiting for
+     * completion.
+     *
+     * Without the `v17` feature, it sends `DEALLOCATE`: the unnamed statement can't be closed.
+     *
+     * See
+     * [PQsendClosePrepared](https://www.postgresql.org/docs/17/libpq-async.html#LIBPQ-PQSENDCLOSEPREPARED).
+     */
+    pub fn send_close_prepared(&self, name: Option<&str>) -> crate::errors::Result {
+        log::trace!("Sending close prepared query {}", name.unwrap_or("anonymous"));
+
+        #[cfg(feature = "v17")]
+        {
+            let c_name = crate::ffi::to_cstr(name.unwrap_or_default());
+
+            let success = unsafe { pq_sys::PQsendClosePrepared(self.into(), c_name.as_ptr()) };
+
+            if success == 1 {
+                Ok(())
+            } else {
+                self.error()
+            }
+        }
+
+        #[cfg(not(feature = "v17"))]
+        self.send_query_with(
+            &self.close_command("DEALLOCATE", name)?,
+            &[],
+            crate::Format::Text,
+        )
+    }
+
+    /**
+     * Submits a request to close the specified portal, without waiting for completion.
+     *
+     * Without the `v17` feature, it sends `CLOSE`: the unnamed portal can't be closed.
+     *
+     * See
+     * [PQsendClosePortal](https://www.postgresql.org/docs/17/libpq-async.html#LIBPQ-PQSENDCLOSEPORTAL).
+     */
+    pub fn send_close_portal(&self, name: Option<&str>) -> crate::errors::Result {
+        log::trace!("Sending close portal {}", name.unwrap_or("anonymous"));
+
+        #[cfg(feature = "v17")]
+        {
+            let c_name = crate::ffi::to_cstr(name.unwrap_or_default());
+
+            let success = unsafe { pq_sys::PQsendClosePortal(self.into(), c_name.as_ptr()) };
+
+            if success == 1 {
+                Ok(())
+            } else {
+                self.error()
+            }
+        }
+
+        #[cfg(not(feature = "v17"))]
+        self.send_query_with(&self.close_command("CLOSE", name)?, &[], crate::Format::Text)
+    }
+
     /**
      * Waits for the next result a prior `send_*` call, and returns it.
      *
@@ -216,6 +290,9 @@ impl Connection {
     /**
      * If input is available from the server, consume it.
      *
+     * A nonblocking connection also flushes its queued output, `libpq::Connection::wants`
+     * includes `Interest::WRITE` while some is left.
+     *
      * See
      * [PQconsumeInput](https://www.postgresql.org/docs/current/libpq-async.html#LIBPQ-PQCONSUMEINPUT).
      */
@@ -224,11 +301,22 @@ impl Connection {
 
         let success = unsafe { pq_sys::PQconsumeInput(self.into()) };
 
-        if success == 1 {
-            Ok(())
-        } else {
-            self.error()
+        if success != 1 {
+            return self.error();
+        }
+
+        if !self.is_non_blocking() {
+            self.wants.set(Interest::READ);
+            return Ok(());
+        }
+
+        match unsafe { pq_sys::PQflush(self.into()) } {
+            0 => self.wants.set(Interest::READ),
+            1 => self.wants.set(Interest::READ | Interest::WRITE),
+            _ => return self.error(),
         }
+
+        Ok(())
     }
 
     /**
@@ -282,10 +370,27 @@ impl Connection {
 
         let status = unsafe { pq_sys::PQflush(self.into()) };
 
+        match status {
+            0 => self.wants.set(Interest::READ),
+            1 => self.wants.set(Interest::READ | Interest::WRITE),
+            _ => (),
+        }
+
         if status == 0 {
             Ok(())
         } else {
             Err(crate::errors::Error::Unknow)
         }
     }
+
+    /**
+     * Returns the socket readiness to wait for, after the last `libpq::Connection::poll`,
+     * `libpq::Connection::flush` or `libpq::Connection::consume_input` call.
+     *
+     * This lets any reactor drive a nonblocking connection: wait for the socket to be ready,
+     * then call the same step again.
+     */
+    pub fn wants(&self) -> Interest {
+        self.wants.get()
+    }
 }
@@ -0,0 +1,159 @@
+/**
+ * Number of rows sent by query when pipeline mode isn't available.
+ */
+#[cfg(not(feature = "v14"))]
+const BATCH_CHUNK_SIZE: usize = 1_000;
+
+/**
+ * Batch execution
+ */
+impl Connection {
+    /**
+     * Executes the prepared statement `name` once by parameter set, and returns the number of rows
+     * affected by each execution.
+     *
+     * With the `v14` feature, the rows are sent in pipeline mode and run in a single implicit
+     * transaction. Otherwise they are sent by chunks of `EXECUTE` statements, a chunk is an
+     * implicit transaction: outside a transaction block, the chunks before a failure are
+     * committed.
+     *
+     * A failure returns `libpq::errors::Error::Batch` with the index of the failed row.
+     *
+     * # Examples
+     *
+     * ```
+     * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+     * let conn = libpq::Connection::new(&dsn).unwrap();
+     * conn.try_exec("CREATE TEMPORARY TABLE t (id int PRIMARY KEY)").unwrap();
+     * conn.prepare(Some("insert"), "INSERT INTO t VALUES ($1) ON CONFLICT DO NOTHING", &[]);
+     *
+     * let counts = conn.execute_many("insert", &[&[&1], &[&2], &[&1]]).unwrap();
+     * assert_eq!(counts, vec![1, 1, 0]);
+     * ```
+     */
+    pub fn execute_many(
+        &self,
+        name: &str,
+        rows: &[&[&dyn crate::types::ToSql]],
+    ) -> crate::errors::Result<Vec<usize>> {
+        #[cfg(feature = "v14")]
+        return self.execute_pipelined(name, rows);
+
+        #[cfg(not(feature = "v14"))]
+        return self.execute_chunked(name, rows);
+    }
+
+    #[cfg(feature = "v14")]
+    fn execute_pipelined(
+        &self,
+        name: &str,
+        rows: &[&[&dyn crate::types::ToSql]],
+    ) -> crate::errors::Result<Vec<usize>> {
+        // Converts all the rows first, an error must not leave a partial pipeline to sync.
+        let rows = rows
+            .iter()
+            .enumerate()
+            .map(|(row, params)| {
+                params
+                    .iter()
+                    .map(|x| Param::from_sql(*x))
+                    .collect::<crate::errors::Result<Vec<_>>>()
+                    .map_err(|error| Self::batch_error(row, error))
+            })
+            .collect::<crate::errors::Result<Vec<_>>>()?;
+
+        let mut pipeline = crate::pipeline::Pipeline::new(self)?;
+
+        let queries = rows
+            .iter()
+            .map(|params| pipeline.query_prepared_with(Some(name), params, crate::Format::Text))
+            .collect::<crate::errors::Result<Vec<_>>>()?;
+        pipeline.sync()?;
+
+        let mut counts = Vec::with_capacity(queries.len());
+
+        for (row, query) in queries.into_iter().enumerate() {
+            let count = pipeline
+                .result(query)
+                .and_then(|x| x.cmd_tuples())
+                .map_err(|error| Self::batch_error(row, error))?;
+
+            counts.push(count);
+        }
+
+        pipeline.finish()?;
+
+        Ok(counts)
+    }
+
+    #[cfg(not(feature = "v14"))]
+    fn execute_chunked(
+        &self,
+        name: &str,
+        rows: &[&[&dyn crate::types::ToSql]],
+    ) -> crate::errors::Result<Vec<usize>> {
+        use std::fmt::Write;
+
+        let name = self.escape_identifier(name)?.to_str()?.to_string();
+        let mut counts = Vec::with_capacity(rows.len());
+
+        for chunk in rows.chunks(BATCH_CHUNK_SIZE) {
+            let offset = counts.len();
+            let mut command = String::new();
+
+            for (row, params) in chunk.iter().enumerate() {
+                let params = params
+                    .iter()
+                    .map(|x| self.literal(*x))
+                    .collect::<crate::errors::Result<Vec<_>>>()
+                    .map_err(|error| Self::batch_error(offset + row, error))?;
+
+                if params.is_empty() {
+                    writeln!(command, "EXECUTE {name};").ok();
+                } else {
+                    writeln!(command, "EXECUTE {name}({});", params.join(", ")).ok();
+                }
+            }
+
+            self.send_query(&command)?;
+
+            let mut error = None;
+
+            // Reads all the results, even after an error, to leave the connection idle.
+            while let Some(result) = self.result() {
+                if error.is_some() {
+                    continue;
+                }
+
+                match self.check(result).and_then(|x| x.cmd_tuples()) {
+                    Ok(count) => counts.push(count),
+                    Err(err) => error = Some(Self::batch_error(counts.len(), err)),
+                }
+            }
+
+            if let Some(error) = error {
+                return Err(error);
+            }
+        }
+
+        Ok(counts)
+    }
+
+    #[cfg(not(feature = "v14"))]
+    fn literal(&self, value: &dyn crate::types::ToSql) -> crate::errors::Result<String> {
+        match value.to_sql(crate::Format::Text)? {
+            Some(value) => Ok(self
+                .escape_literal(std::str::from_utf8(&value)?)?
+                .to_str()?
+                .to_string()),
+            None => Ok("NULL".to_string()),
+        }
+    }
+
+    fn batch_error(row: usize, error: crate::errors::Error) -> crate::errors::Error {
+        crate::errors::Error::Batch {
+            row,
+            error: Box::new(error),
+        }
+    }
+}
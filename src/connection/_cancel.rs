@@ -12,4 +12,17 @@ impl Connection {
     pub fn cancel(&self) -> crate::connection::Cancel {
         unsafe { pq_sys::PQgetCancel(self.into()) }.into()
     }
+
+    /**
+     * Creates a connection to cancel the query in progress on this connection, see
+     * `libpq::connection::CancelConnection`.
+     *
+     * See
+     * [PQcancelCreate](https://www.postgresql.org/docs/17/libpq-cancel.html#LIBPQ-PQCANCELCREATE).
+     */
+    #[cfg(feature = "v17")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "v17")))]
+    pub fn cancel_connection(&self) -> crate::errors::Result<crate::connection::CancelConnection> {
+        crate::connection::CancelConnection::new(self)
+    }
 }
@@ -38,6 +38,23 @@ impl Connection {
         .try_into()
     }
 
+    /**
+     * Makes a new connection to the database server, after validating the configuration.
+     *
+     * See [PQconnectdbParams](https://www.postgresql.org/docs/current/libpq-connect.html#LIBPQ-PQCONNECTDBPARAMS).
+     */
+    pub fn with_config(config: &crate::connection::Config) -> crate::errors::Result<Self> {
+        log::trace!("Connecting with config '{}'", config);
+
+        config.validate()?;
+
+        let (keywords, values): (Vec<_>, Vec<_>) = config.params().unzip();
+        let (_c_keywords, ptr_keywords) = crate::ffi::vec_to_nta(&keywords);
+        let (_c_values, ptr_values) = crate::ffi::vec_to_nta(&values);
+
+        unsafe { pq_sys::PQconnectdbParams(ptr_keywords.as_ptr(), ptr_values.as_ptr(), 0) }.try_into()
+    }
+
     /**
      * Make a connection to the database server in a nonblocking manner.
      *
@@ -71,6 +88,24 @@ impl Connection {
         .try_into()
     }
 
+    /**
+     * Make a connection to the database server in a nonblocking manner, after validating the
+     * configuration.
+     *
+     * See [PQconnectStartParams](https://www.postgresql.org/docs/current/libpq-connect.html#LIBPQ-PQCONNECTSTARTPARAMS).
+     */
+    pub fn start_config(config: &crate::connection::Config) -> crate::errors::Result<Self> {
+        log::trace!("Starting connection with config '{}'", config);
+
+        config.validate()?;
+
+        let (keywords, values): (Vec<_>, Vec<_>) = config.params().unzip();
+        let (_c_keywords, ptr_keywords) = crate::ffi::vec_to_nta(&keywords);
+        let (_c_values, ptr_values) = crate::ffi::vec_to_nta(&values);
+
+        unsafe { pq_sys::PQconnectStartParams(ptr_keywords.as_ptr(), ptr_values.as_ptr(), 0) }.try_into()
+    }
+
     /**
      * Makes a new connection to the database server.
      *
@@ -127,7 +162,10 @@ impl Connection {
      * See [PQconnectPoll](https://www.postgresql.org/docs/current/libpq-connect.html#LIBPQ-PQCONNECTPOLL).
      */
     pub fn poll(&self) -> crate::poll::Status {
-        unsafe { pq_sys::PQconnectPoll(self.into()) }.into()
+        let status: crate::poll::Status = unsafe { pq_sys::PQconnectPoll(self.into()) }.into();
+        self.wants.set(status.into());
+
+        status
     }
 
     /**
@@ -146,6 +184,7 @@ impl Connection {
      */
     pub fn reset_start(&self) {
         unsafe { pq_sys::PQresetStart(self.into()) };
+        self.wants.set(Interest::WRITE);
     }
 
     /**
@@ -153,7 +192,10 @@ impl Connection {
      * [PQresetPoll](https://www.postgresql.org/docs/current/libpq-connect.html#LIBPQ-PQRESETPOLL).
      */
     pub fn reset_poll(&self) -> crate::poll::Status {
-        unsafe { pq_sys::PQresetPoll(self.into()) }.into()
+        let status: crate::poll::Status = unsafe { pq_sys::PQresetPoll(self.into()) }.into();
+        self.wants.set(status.into());
+
+        status
     }
 
     /**
@@ -227,4 +269,21 @@ impl Connection {
 
         Ok(infos)
     }
+
+    /**
+     * Opens a new session with the same connection options, see `libpq::Connection::info`.
+     *
+     * The session state, like prepared statements or settings changed with `SET`, isn't copied.
+     */
+    pub fn try_clone(&self) -> crate::errors::Result<Self> {
+        let config = self
+            .info()?
+            .into_values()
+            .filter_map(|info| info.val.map(|val| (info.keyword, val)))
+            .fold(crate::connection::Config::new(), |config, (keyword, val)| {
+                config.set(&keyword, &val)
+            });
+
+        Self::with_config(&config)
+    }
 }
@@ -74,4 +74,33 @@ impl Connection {
             nbytes => Ok(PqBytes::from_raw(ptr as *const u8, nbytes as usize)),
         }
     }
+
+    /**
+     * Receives the next row of a `COPY TO STDOUT`, returns `None` at the end of the copy.
+     */
+    pub(crate) fn next_copy_data(&self) -> crate::errors::Result<Option<PqBytes>> {
+        let mut ptr = std::ptr::null_mut();
+
+        let success = unsafe { pq_sys::PQgetCopyData(self.into(), &mut ptr, 0) };
+
+        match success {
+            -2 => self.error(),
+            -1 => Ok(None),
+            nbytes => Ok(Some(PqBytes::from_raw(ptr as *const u8, nbytes as usize))),
+        }
+    }
+
+    /**
+     * Starts a `COPY FROM STDIN` command, and returns a writer for its data.
+     */
+    pub fn copy_in(&self, command: &str) -> crate::errors::Result<crate::copy::CopyInWriter<'_>> {
+        crate::copy::CopyInWriter::new(self, command)
+    }
+
+    /**
+     * Starts a `COPY TO STDOUT` command, and returns a reader for its data.
+     */
+    pub fn copy_out(&self, command: &str) -> crate::errors::Result<crate::copy::CopyOutReader<'_>> {
+        crate::copy::CopyOutReader::new(self, command)
+    }
 }
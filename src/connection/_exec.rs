@@ -14,6 +14,15 @@ impl Connection {
         unsafe { pq_sys::PQexec(self.into(), c_query.as_ptr()) }.into()
     }
 
+    /**
+     * Same as `libpq::Connection::exec`, but a failed command returns an error.
+     *
+     * The error is a [`crate::errors::DbError`] when the server reported it.
+     */
+    pub fn try_exec(&self, query: &str) -> crate::errors::Result<crate::PQResult> {
+        self.check(self.exec(query))
+    }
+
     /**
      * Submits a command to the server and waits for the result, with the ability to pass
      * parameters separately from the SQL command text.
@@ -28,37 +37,85 @@ impl Connection {
         param_formats: &[crate::Format],
         result_format: crate::Format,
     ) -> crate::PQResult {
-        let (values, formats, lengths) = Self::transform_params(param_values, param_formats);
+        let params = Self::transform_params(param_types, param_values, param_formats);
 
-        Self::trace_query("Sending", command, param_types, param_values, param_formats);
+        self.exec_with(command, &params, result_format)
+            .expect("legacy text parameters are always null terminated")
+    }
+
+    /**
+     * Same as `libpq::Connection::exec_params`, but a failed command returns an error.
+     */
+    pub fn try_exec_params(
+        &self,
+        command: &str,
+        param_types: &[crate::Oid],
+        param_values: &[Option<Vec<u8>>],
+        param_formats: &[crate::Format],
+        result_format: crate::Format,
+    ) -> crate::errors::Result<crate::PQResult> {
+        self.check(self.exec_params(
+            command,
+            param_types,
+            param_values,
+            param_formats,
+            result_format,
+        ))
+    }
+
+    /**
+     * Same as `libpq::Connection::exec_params`, with owned parameters.
+     *
+     * Returns an error if a text parameter contains a null byte, instead of sending a truncated
+     * value.
+     *
+     * See [PQexecParams](https://www.postgresql.org/docs/current/libpq-exec.html#LIBPQ-PQEXECPARAMS).
+     */
+    pub fn exec_with(
+        &self,
+        command: &str,
+        params: &[Param],
+        result_format: crate::Format,
+    ) -> crate::errors::Result<crate::PQResult> {
+        let raw = RawParams::new(params)?;
+
+        Self::trace_query("Sending", command, &[], params);
 
         let c_command = crate::ffi::to_cstr(command);
 
-        unsafe {
+        let result = unsafe {
             pq_sys::PQexecParams(
                 self.into(),
                 c_command.as_ptr(),
-                values.len() as i32,
-                if param_types.is_empty() {
-                    std::ptr::null()
-                } else {
-                    param_types.as_ptr()
-                },
-                values.as_ptr(),
-                if lengths.is_empty() {
-                    std::ptr::null()
-                } else {
-                    lengths.as_ptr()
-                },
-                if formats.is_empty() {
-                    std::ptr::null()
-                } else {
-                    formats.as_ptr()
-                },
+                raw.len(),
+                raw.types(),
+                raw.values(),
+                raw.lengths(),
+                raw.formats(),
                 result_format as i32,
             )
-        }
-        .into()
+        };
+
+        Ok(result.into())
+    }
+
+    /**
+     * Same as `libpq::Connection::exec_params`, with parameters converted from rust values.
+     *
+     * Types and formats of parameters are given by [`crate::types::ToSql`].
+     */
+    pub fn exec_typed(
+        &self,
+        command: &str,
+        params: &[&dyn crate::types::ToSql],
+        result_format: crate::Format,
+    ) -> crate::errors::Result<crate::PQResult> {
+        let params = params
+            .iter()
+            .map(|x| Param::from_sql(*x))
+            .collect::<crate::errors::Result<Vec<_>>>()?;
+
+        self.exec_with(command, &params, result_format)
     }
 
     /**
@@ -73,7 +130,7 @@ impl Connection {
         param_types: &[crate::Oid],
     ) -> crate::PQResult {
         let prefix = format!("Prepare {}", name.unwrap_or("anonymous"));
-        Self::trace_query(&prefix, query, param_types, &[], &[]);
+        Self::trace_query(&prefix, query, param_types, &[]);
 
         let c_name = crate::ffi::to_cstr(name.unwrap_or_default());
         let c_query = crate::ffi::to_cstr(query);
@@ -103,33 +160,75 @@ impl Connection {
         param_formats: &[crate::Format],
         result_format: crate::Format,
     ) -> crate::PQResult {
-        let prefix = format!("Execute {} prepared query", name.unwrap_or("anonymous"));
-        Self::trace_query(&prefix, "", &[], param_values, param_formats);
+        let params = Self::transform_params(&[], param_values, param_formats);
 
-        let (values, formats, lengths) = Self::transform_params(param_values, param_formats);
+        self.exec_prepared_with(name, &params, result_format)
+            .expect("legacy text parameters are always null terminated")
+    }
+
+    /**
+     * Same as `libpq::Connection::exec_prepared`, but a failed command returns an error.
+     */
+    pub fn try_exec_prepared(
+        &self,
+        name: Option<&str>,
+        param_values: &[Option<Vec<u8>>],
+        param_formats: &[crate::Format],
+        result_format: crate::Format,
+    ) -> crate::errors::Result<crate::PQResult> {
+        self.check(self.exec_prepared(name, param_values, param_formats, result_format))
+    }
+
+    /**
+     * Same as `libpq::Connection::exec_prepared`, with owned parameters.
+     *
+     * Parameter types are ignored, they are given by the prepared statement.
+     *
+     * See [PQexecPrepared](https://www.postgresql.org/docs/current/libpq-exec.html#LIBPQ-PQEXECPREPARED).
+     */
+    pub fn exec_prepared_with(
+        &self,
+        name: Option<&str>,
+        params: &[Param],
+        result_format: crate::Format,
+    ) -> crate::errors::Result<crate::PQResult> {
+        let raw = RawParams::new(params)?;
+
+        let prefix = format!("Execute {} prepared query", name.unwrap_or("anonymous"));
+        Self::trace_query(&prefix, "", &[], params);
 
         let c_name = crate::ffi::to_cstr(name.unwrap_or_default());
 
-        unsafe {
+        let result = unsafe {
             pq_sys::PQexecPrepared(
                 self.into(),
                 c_name.as_ptr(),
-                values.len() as i32,
-                values.as_ptr(),
-                if lengths.is_empty() {
-                    std::ptr::null()
-                } else {
-                    lengths.as_ptr()
-                },
-                if formats.is_empty() {
-                    std::ptr::null()
-                } else {
-                    formats.as_ptr()
-                },
+                raw.len(),
+                raw.values(),
+                raw.lengths(),
+                raw.formats(),
                 result_format as i32,
             )
-        }
-        .into()
+        };
+
+        Ok(result.into())
+    }
+
+    /**
+     * Same as `libpq::Connection::exec_prepared`, with parameters converted from rust values.
+     */
+    pub fn exec_prepared_typed(
+        &self,
+        name: Option<&str>,
+        params: &[&dyn crate::types::ToSql],
+        result_format: crate::Format,
+    ) -> crate::errors::Result<crate::PQResult> {
+        let params = params
+            .iter()
+            .map(|x| Param::from_sql(*x))
+            .collect::<crate::errors::Result<Vec<_>>>()?;
+
+        self.exec_prepared_with(name, &params, result_format)
     }
 
     /**
@@ -155,6 +254,80 @@ impl Connection {
         unsafe { pq_sys::PQdescribePortal(self.into(), c_name.as_ptr()) }.into()
     }
 
+    /**
+     * Submits a request to close the specified prepared statement, and waits for completion.
+     *
+     * Without the `v17` feature, it runs `DEALLOCATE`: closing the unnamed statement is an
+     * `InvalidOption` error, and closing an unknown statement is an error.
+     *
+     * See [PQclosePrepared](https://www.postgresql.org/docs/17/libpq-exec.html#LIBPQ-PQCLOSEPREPARED).
+     */
+    pub fn close_prepared(&self, name: Option<&str>) -> crate::errors::Result<crate::PQResult> {
+        log::trace!("Close prepared query {}", name.unwrap_or("anonymous"));
+
+        #[cfg(feature = "v17")]
+        let result = {
+            let c_name = crate::ffi::to_cstr(name.unwrap_or_default());
+
+            unsafe { pq_sys::PQclosePrepared(self.into(), c_name.as_ptr()) }.into()
+        };
+
+        #[cfg(not(feature = "v17"))]
+        let result = self.exec(&self.close_command("DEALLOCATE", name)?);
+
+        self.check(result)
+    }
+
+    /**
+     * Submits a request to close the specified portal, and waits for completion.
+     *
+     * Without the `v17` feature, it runs `CLOSE`: closing the unnamed portal is an
+     * `InvalidOption` error, and closing an unknown portal is an error.
+     *
+     * See [PQclosePortal](https://www.postgresql.org/docs/17/libpq-exec.html#LIBPQ-PQCLOSEPORTAL).
+     */
+    pub fn close_portal(&self, name: Option<&str>) -> crate::errors::Result<crate::PQResult> {
+        log::trace!("Close portal {}", name.unwrap_or("anonymous"));
+
+        #[cfg(feature = "v17")]
+        let result = {
+            let c_name = crate::ffi::to_cstr(name.unwrap_or_default());
+
+            unsafe { pq_sys::PQclosePortal(self.into(), c_name.as_ptr()) }.into()
+        };
+
+        #[cfg(not(feature = "v17"))]
+        let result = self.exec(&self.close_command("CLOSE", name)?);
+
+        self.check(result)
+    }
+
+    /**
+     * Creates a prepared statement with a generated name, closed when the returned handle is
+     * dropped.
+     */
+    pub fn statement(
+        &self,
+        query: &str,
+        param_types: &[crate::Oid],
+    ) -> crate::errors::Result<crate::connection::Statement<'_>> {
+        let name = crate::connection::Statement::next_name();
+
+        self.check(self.prepare(Some(&name), query, param_types))?;
+
+        Ok(crate::connection::Statement::new(self, name))
+    }
+
+    #[cfg(not(feature = "v17"))]
+    fn close_command(&self, command: &str, name: Option<&str>) -> crate::errors::Result<String> {
+        let Some(name) = name else {
+            return Err(crate::errors::Error::InvalidOption("name".to_string()));
+        };
+        let name = self.escape_identifier(name)?;
+
+        Ok(format!("{command} {}", name.to_str()?))
+    }
+
     /**
      * Escape a string for use within an SQL command.
      *
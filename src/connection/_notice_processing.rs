@@ -25,6 +25,97 @@ impl Connection {
         proc: NoticeReceiver,
         arg: *mut raw::c_void,
     ) -> NoticeReceiver {
-        pq_sys::PQsetNoticeReceiver(self.into(), proc, arg)
+        let previous = pq_sys::PQsetNoticeReceiver(self.into(), proc, arg);
+
+        if proc.is_some() {
+            let mut notices = self.notices.lock();
+            notices.installed = false;
+            notices.handler = None;
+            notices.fallback = (proc, arg);
+        }
+
+        previous
+    }
+
+    /**
+     * Calls `f` for each notice or warning sent by the server, replacing the default receiver
+     * which prints them on stderr.
+     *
+     * The closure is owned by the connection and dropped with it, or when replaced.
+     *
+     * See [PQsetNoticeReceiver](https://www.postgresql.org/docs/current/libpq-notice-processing.html).
+     */
+    pub fn on_notice<F>(&self, f: F)
+    where
+        F: FnMut(crate::connection::Notice) + Send + 'static,
+    {
+        self.install_notice_receiver();
+        self.notices.lock().handler = Some(Box::new(f));
+    }
+
+    /**
+     * Routes notices to the `log` crate, at the level matching their severity.
+     */
+    pub fn log_notices(&self) {
+        self.on_notice(|notice| log::log!(notice.level(), "{notice}"));
+    }
+
+    /**
+     * Runs `f` and returns the notices received meanwhile, in addition to passing them to the
+     * current receiver.
+     *
+     * # Examples
+     *
+     * ```
+     * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+     * let conn = libpq::Connection::new(&dsn).unwrap();
+     *
+     * let (result, notices) = conn.collect_notices(|conn| {
+     *     conn.try_exec("CREATE TEMPORARY TABLE IF NOT EXISTS t (id int)")
+     * });
+     * assert!(result.is_ok());
+     * assert!(notices.is_empty());
+     *
+     * let (_, notices) = conn.collect_notices(|conn| {
+     *     conn.try_exec("CREATE TEMPORARY TABLE IF NOT EXISTS t (id int)")
+     * });
+     * assert_eq!(notices[0].code, "42P07");
+     * ```
+     */
+    pub fn collect_notices<F, T>(&self, f: F) -> (T, Vec<crate::connection::Notice>)
+    where
+        F: FnOnce(&Self) -> T,
+    {
+        self.install_notice_receiver();
+
+        let outer = self.notices.lock().collected.replace(Vec::new());
+        let value = f(self);
+
+        let mut notices = self.notices.lock();
+        let collected = std::mem::replace(&mut notices.collected, outer).unwrap_or_default();
+
+        if let Some(outer) = &mut notices.collected {
+            outer.extend_from_slice(&collected);
+        }
+
+        (value, collected)
+    }
+
+    fn install_notice_receiver(&self) {
+        let mut notices = self.notices.lock();
+
+        if notices.installed {
+            return;
+        }
+
+        let arg = self.notices.register();
+        let previous =
+            unsafe { pq_sys::PQsetNoticeReceiver(self.into(), Some(notice::receive), arg) };
+
+        // The default receiver ignores its argument.
+        if notices.fallback.0.is_none() {
+            notices.fallback = (previous, std::ptr::null_mut());
+        }
+        notices.installed = true;
     }
 }
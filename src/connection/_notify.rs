@@ -15,4 +15,20 @@ impl Connection {
             Some(raw.into())
         }
     }
+
+    /**
+     * Sends a notification on `channel`, the channel and payload are passed as parameters so they
+     * don't need to be escaped.
+     *
+     * See [pg_notify](https://www.postgresql.org/docs/current/sql-notify.html).
+     */
+    pub fn notify(&self, channel: &str, payload: &str) -> crate::errors::Result {
+        let result = self.exec_with(
+            "SELECT pg_notify($1, $2)",
+            &[channel.into(), payload.into()],
+            crate::Format::Text,
+        )?;
+
+        self.check(result).map(|_| ())
+    }
 }
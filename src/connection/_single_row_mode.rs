@@ -19,4 +19,109 @@ impl Connection {
             Err(crate::errors::Error::Unknow)
         }
     }
+
+    /**
+     * Select chunked mode for the currently-executing query, results contain up to `chunk_size`
+     * rows.
+     *
+     * A `chunk_size` that doesn't fit in an `i32` is an `InvalidOption` error.
+     *
+     * See
+     * [PQsetChunkedRowsMode](https://www.postgresql.org/docs/17/libpq-single-row-mode.html#LIBPQ-PQSETCHUNKEDROWSMODE).
+     */
+    #[cfg(feature = "v17")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "v17")))]
+    pub fn set_chunked_rows_mode(&self, chunk_size: usize) -> crate::errors::Result {
+        log::trace!("Set chunked rows mode ({chunk_size} rows)");
+
+        let chunk_size = i32::try_from(chunk_size)
+            .map_err(|_| crate::errors::Error::InvalidOption("chunk_size".to_string()))?;
+        let success = unsafe { pq_sys::PQsetChunkedRowsMode(self.into(), chunk_size) };
+
+        if success == 1 {
+            Ok(())
+        } else {
+            Err(crate::errors::Error::Unknow)
+        }
+    }
+
+    /**
+     * Sends a query in single-row mode, and returns an iterator over its rows: the memory used
+     * doesn't depend on the result size.
+     *
+     * # Examples
+     *
+     * ```
+     * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+     * let conn = libpq::Connection::new(&dsn).unwrap();
+     *
+     * let mut sum = 0;
+     * for result in conn.query_stream("SELECT generate_series(1, $1)", &[&100]).unwrap() {
+     *     sum += result.unwrap().get::<i32>(0, 0).unwrap();
+     * }
+     * assert_eq!(sum, 5050);
+     * ```
+     */
+    pub fn query_stream(
+        &self,
+        command: &str,
+        params: &[&dyn crate::types::ToSql],
+    ) -> crate::errors::Result<crate::connection::RowStream<'_>> {
+        self.send_stream(command, params, || self.set_single_row_mode())
+    }
+
+    /**
+     * Same as `libpq::Connection::query_stream` in chunked mode: each result holds up to
+     * `chunk_size` rows, which is faster than one result by row.
+     *
+     * # Examples
+     *
+     * ```
+     * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+     * let conn = libpq::Connection::new(&dsn).unwrap();
+     *
+     * let mut sum = 0;
+     * for result in conn.query_chunks("SELECT generate_series(1, 100)", &[], 30).unwrap() {
+     *     let result = result.unwrap();
+     *
+     *     for row in 0..result.ntuples() {
+     *         sum += result.get::<i32>(row, 0).unwrap();
+     *     }
+     * }
+     * assert_eq!(sum, 5050);
+     * ```
+     */
+    #[cfg(feature = "v17")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "v17")))]
+    pub fn query_chunks(
+        &self,
+        command: &str,
+        params: &[&dyn crate::types::ToSql],
+        chunk_size: usize,
+    ) -> crate::errors::Result<crate::connection::RowStream<'_>> {
+        self.send_stream(command, params, || self.set_chunked_rows_mode(chunk_size))
+    }
+
+    fn send_stream(
+        &self,
+        command: &str,
+        params: &[&dyn crate::types::ToSql],
+        mode: impl FnOnce() -> crate::errors::Result,
+    ) -> crate::errors::Result<crate::connection::RowStream<'_>> {
+        let params = params
+            .iter()
+            .map(|x| Param::from_sql(*x))
+            .collect::<crate::errors::Result<Vec<_>>>()?;
+
+        self.send_query_with(command, &params, crate::Format::Text)?;
+
+        let mut stream = crate::connection::RowStream::new(self);
+
+        if let Err(err) = mode() {
+            stream.drain();
+            return Err(err);
+        }
+
+        Ok(stream)
+    }
 }
@@ -0,0 +1,57 @@
+/**
+ * [Transactions](https://www.postgresql.org/docs/current/tutorial-transactions.html)
+ */
+impl Connection {
+    /**
+     * Starts a transaction with the session default modes.
+     *
+     * Returns an error if the connection is already in a transaction block.
+     */
+    pub fn transaction(&self) -> crate::errors::Result<crate::transaction::Transaction<'_>> {
+        self.transaction_with(&crate::transaction::Options::default())
+    }
+
+    /**
+     * Starts a transaction with the given modes.
+     */
+    pub fn transaction_with(
+        &self,
+        options: &crate::transaction::Options,
+    ) -> crate::errors::Result<crate::transaction::Transaction<'_>> {
+        crate::transaction::Transaction::begin(self, options)
+    }
+
+    /**
+     * Runs `f` in a transaction, committed if `f` succeeds.
+     *
+     * The whole transaction is run again when it fails with a serialization failure or a
+     * deadlock, see `libpq::State::is_retryable`. Once the attempts are exhausted, the last error
+     * is returned in `libpq::errors::Error::Retry`.
+     *
+     * # Examples
+     *
+     * ```
+     * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+     * let conn = libpq::Connection::new(&dsn).unwrap();
+     *
+     * let options = libpq::transaction::Options::new()
+     *     .isolation_level(libpq::transaction::IsolationLevel::Serializable);
+     * let retry = libpq::transaction::Retry::new().options(options).attempts(3);
+     *
+     * let one = conn
+     *     .transaction_with_retry(&retry, |tx| tx.try_exec("SELECT 1")?.get::<i32>(0, 0))
+     *     .unwrap();
+     * assert_eq!(one, 1);
+     * ```
+     */
+    pub fn transaction_with_retry<T, F>(
+        &self,
+        retry: &crate::transaction::Retry,
+        f: F,
+    ) -> crate::errors::Result<T>
+    where
+        F: FnMut(&mut crate::transaction::Transaction<'_>) -> crate::errors::Result<T>,
+    {
+        retry.run(self, f)
+    }
+}
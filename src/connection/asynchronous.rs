@@ -0,0 +1,422 @@
+use ::tokio::io::unix::AsyncFd;
+use ::tokio::io::Interest;
+use ::tokio::io::Ready;
+
+#[derive(Debug)]
+struct Socket(std::os::unix::io::RawFd);
+
+impl std::os::unix::io::AsRawFd for Socket {
+    fn as_raw_fd(&self) -> std::os::unix::io::RawFd {
+        self.0
+    }
+}
+
+/**
+ * A nonblocking connection driven by the tokio reactor.
+ *
+ * Commands are sent with the `send_*` functions, then the socket is polled until all results
+ * are received.
+ *
+ * The `exec*` and `prepare` futures can be dropped before completion: the results left by the
+ * abandoned command are discarded by the next of these calls, which waits for the command to
+ * end first.
+ *
+ * # Examples
+ *
+ * ```
+ * # #[tokio::main(flavor = "current_thread")]
+ * # async fn main() {
+ * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+ * let mut conn = libpq::connection::AsyncConnection::connect(&dsn).await.unwrap();
+ *
+ * let result = conn.exec("SELECT 1").await.unwrap();
+ * assert_eq!(result.value(0, 0), Some(&b"1"[..]));
+ * # }
+ * ```
+ */
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub struct AsyncConnection {
+    // Declared before `conn`, the socket must be deregistered before libpq closes it.
+    fd: AsyncFd<Socket>,
+    conn: crate::Connection,
+    // An `exec*` or `prepare` future was dropped before reading all the results.
+    pending: bool,
+}
+
+impl AsyncConnection {
+    /**
+     * Makes a new connection to the database server, without blocking the runtime.
+     *
+     * See
+     * [PQconnectStart](https://www.postgresql.org/docs/current/libpq-connect.html#LIBPQ-PQCONNECTSTART).
+     */
+    pub async fn connect(dsn: &str) -> crate::errors::Result<Self> {
+        let conn = crate::Connection::start(dsn)?;
+        let mut status = crate::poll::Status::Writing;
+
+        loop {
+            let interest = match status {
+                crate::poll::Status::Ok => break,
+                crate::poll::Status::Failed => return conn.error(),
+                crate::poll::Status::Reading => Some(Interest::READABLE),
+                crate::poll::Status::Writing => Some(Interest::WRITABLE),
+                crate::poll::Status::Active => None,
+            };
+
+            // libpq may open a new socket on each poll, when it tries the next host, so the
+            // socket is registered only while waiting.
+            if let Some(interest) = interest {
+                let fd = Self::register(&conn)?;
+                fd.ready(interest).await?.clear_ready();
+            }
+
+            status = conn.poll();
+        }
+
+        Self::new(conn)
+    }
+
+    /**
+     * Wraps an established connection, it is switched to nonblocking mode.
+     */
+    pub fn new(conn: crate::Connection) -> crate::errors::Result<Self> {
+        conn.set_non_blocking(true)?;
+
+        let fd = Self::register(&conn)?;
+
+        Ok(Self {
+            fd,
+            conn,
+            pending: false,
+        })
+    }
+
+    fn register(conn: &crate::Connection) -> crate::errors::Result<AsyncFd<Socket>> {
+        let socket = Socket(conn.socket()?);
+
+        // SAFETY: the socket is owned by libpq, it stays open until the connection is closed or
+        // polled, and the registration is dropped before.
+        let fd = unsafe { AsyncFd::register(socket) }.map_err(|err| err.into_parts().1)?;
+
+        Ok(fd)
+    }
+
+    /**
+     * Returns the underlying connection.
+     *
+     * The socket is registered in the reactor once: resetting the connection through this
+     * reference may change the socket, and leaves this wrapper unusable.
+     */
+    pub fn connection(&self) -> &crate::Connection {
+        &self.conn
+    }
+
+    /**
+     * Submits a command to the server and waits for the result.
+     *
+     * If the command contains several queries, only the last result is returned, like
+     * `libpq::Connection::exec`.
+     */
+    pub async fn exec(&mut self, query: &str) -> crate::errors::Result<crate::PQResult> {
+        self.discard().await?;
+        self.conn.send_query(query)?;
+        self.last_result().await
+    }
+
+    /**
+     * Submits a command to the server and waits for the result, with the ability to pass
+     * parameters separately from the SQL command text.
+     *
+     * See `libpq::Connection::exec_params`.
+     */
+    pub async fn exec_params(
+        &mut self,
+        command: &str,
+        param_types: &[crate::Oid],
+        param_values: &[Option<Vec<u8>>],
+        param_formats: &[crate::Format],
+        result_format: crate::Format,
+    ) -> crate::errors::Result<crate::PQResult> {
+        self.discard().await?;
+        self.conn.send_query_params(
+            command,
+            param_types,
+            param_values,
+            param_formats,
+            result_format,
+        )?;
+        self.last_result().await
+    }
+
+    /**
+     * Submits a request to create a prepared statement with the given parameters, and waits for
+     * completion.
+     *
+     * See `libpq::Connection::prepare`.
+     */
+    pub async fn prepare(
+        &mut self,
+        name: Option<&str>,
+        query: &str,
+        param_types: &[crate::Oid],
+    ) -> crate::errors::Result<crate::PQResult> {
+        self.discard().await?;
+        self.conn.send_prepare(name, query, param_types)?;
+        self.last_result().await
+    }
+
+    /**
+     * Sends a request to execute a prepared statement with given parameters, and waits for the
+     * result.
+     *
+     * See `libpq::Connection::exec_prepared`.
+     */
+    pub async fn exec_prepared(
+        &mut self,
+        name: Option<&str>,
+        param_values: &[Option<Vec<u8>>],
+        param_formats: &[crate::Format],
+        result_format: crate::Format,
+    ) -> crate::errors::Result<crate::PQResult> {
+        self.discard().await?;
+        self.conn
+            .send_query_prepared(name, param_values, param_formats, result_format)?;
+        self.last_result().await
+    }
+
+    /**
+     * Sends the queued data to the server, waiting for the socket to be writable.
+     *
+     * Input is consumed while waiting, so the server is never blocked sending us data.
+     *
+     * See [PQflush](https://www.postgresql.org/docs/current/libpq-async.html#LIBPQ-PQFLUSH).
+     */
+    pub async fn flush(&mut self) -> crate::errors::Result {
+        use crate::connection::Interest as Wants;
+
+        loop {
+            match self.conn.flush() {
+                Ok(()) => return Ok(()),
+                Err(_) if self.conn.wants().contains(Wants::WRITE) => (),
+                Err(_) => return self.conn.error(),
+            }
+
+            let mut guard = self
+                .fd
+                .ready(Interest::READABLE | Interest::WRITABLE)
+                .await?;
+            let ready = guard.ready();
+
+            // The send blocked before this wait, the next one waits for a new edge.
+            if ready.is_writable() {
+                guard.clear_ready_matching(Ready::WRITABLE);
+            }
+
+            if ready.is_readable() {
+                self.conn.consume_input()?;
+
+                if self.is_drained() {
+                    guard.clear_ready_matching(Ready::READABLE);
+                }
+            }
+        }
+    }
+
+    /**
+     * Waits for the next result of a prior `send_*` call, and returns it.
+     *
+     * See `libpq::Connection::result`.
+     */
+    pub async fn result(&mut self) -> crate::errors::Result<Option<crate::PQResult>> {
+        self.flush().await?;
+
+        while self.conn.is_busy() {
+            let mut guard = self.fd.readable().await?;
+            self.conn.consume_input()?;
+
+            // The readiness is only needed again if the result isn't complete.
+            if self.conn.is_busy() && self.is_drained() {
+                guard.clear_ready();
+            }
+        }
+
+        Ok(self.conn.result())
+    }
+
+    /**
+     * Waits for the socket to be readable and consumes the available input, notifications are
+     * then returned by `libpq::Connection::notifies`.
+     */
+    pub async fn consume_input(&mut self) -> crate::errors::Result {
+        let mut guard = self.fd.readable().await?;
+        self.conn.consume_input()?;
+
+        if self.is_drained() {
+            guard.clear_ready();
+        }
+
+        Ok(())
+    }
+
+    /**
+     * `PQconsumeInput` may leave data in the socket without reporting it, but the readiness is
+     * edge-triggered: it must be kept until the socket is drained. Only checked before waiting.
+     */
+    fn is_drained(&self) -> bool {
+        let mut byte = 0u8;
+        let read = unsafe {
+            libc::recv(
+                self.fd.get_ref().0,
+                &mut byte as *mut u8 as *mut libc::c_void,
+                1,
+                libc::MSG_PEEK | libc::MSG_DONTWAIT,
+            )
+        };
+
+        read < 0 && std::io::Error::last_os_error().kind() == std::io::ErrorKind::WouldBlock
+    }
+
+    async fn discard(&mut self) -> crate::errors::Result {
+        if self.pending {
+            while self.result().await?.is_some() {}
+            self.pending = false;
+        }
+
+        Ok(())
+    }
+
+    async fn last_result(&mut self) -> crate::errors::Result<crate::PQResult> {
+        let mut last = None;
+
+        self.pending = true;
+        while let Some(result) = self.result().await? {
+            last = Some(result);
+        }
+        self.pending = false;
+
+        match last {
+            Some(result) => Ok(result),
+            None => self.conn.error(),
+        }
+    }
+}
+
+impl From<AsyncConnection> for crate::Connection {
+    fn from(conn: AsyncConnection) -> Self {
+        let AsyncConnection { fd, conn, .. } = conn;
+        drop(fd);
+
+        conn
+    }
+}
+
+#[cfg(test)]
+mod test {
+    async fn new_conn() -> super::AsyncConnection {
+        super::AsyncConnection::connect(&crate::test::dsn())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn connect() {
+        let conn = new_conn().await;
+
+        assert_eq!(conn.connection().status(), crate::connection::Status::Ok);
+        assert!(conn.connection().is_non_blocking());
+    }
+
+    #[tokio::test]
+    async fn connect_failed() {
+        assert!(super::AsyncConnection::connect("host=localhost port=1")
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn exec() {
+        let mut conn = new_conn().await;
+
+        let result = conn.exec("SELECT 1; SELECT 2").await.unwrap();
+        assert_eq!(result.value(0, 0), Some(&b"2"[..]));
+
+        let result = conn.exec("SELECT 1/0").await.unwrap();
+        assert_eq!(result.status(), crate::Status::FatalError);
+    }
+
+    #[tokio::test]
+    async fn exec_dropped() {
+        let mut conn = new_conn().await;
+
+        // Polls the query once, then drops it.
+        tokio::select! {
+            biased;
+            _ = conn.exec("SELECT pg_sleep(0.2), 1") => panic!("The query should be pending"),
+            _ = std::future::ready(()) => (),
+        }
+
+        let result = conn.exec("SELECT 2").await.unwrap();
+        assert_eq!(result.value(0, 0), Some(&b"2"[..]));
+    }
+
+    #[tokio::test]
+    async fn exec_params() {
+        let mut conn = new_conn().await;
+
+        let result = conn
+            .exec_params(
+                "SELECT $1",
+                &[],
+                &[Some(b"foo".to_vec())],
+                &[],
+                crate::Format::Text,
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.value(0, 0), Some(&b"foo"[..]));
+    }
+
+    #[tokio::test]
+    async fn exec_prepared() {
+        let mut conn = new_conn().await;
+
+        let result = conn
+            .prepare(Some("async"), "SELECT $1::int + 1", &[])
+            .await
+            .unwrap();
+        assert_eq!(result.status(), crate::Status::CommandOk);
+
+        let result = conn
+            .exec_prepared(
+                Some("async"),
+                &[Some(b"1".to_vec())],
+                &[],
+                crate::Format::Text,
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.value(0, 0), Some(&b"2"[..]));
+    }
+
+    #[tokio::test]
+    async fn large_query() {
+        let mut conn = new_conn().await;
+        let value = "a".repeat(1024 * 1024);
+
+        let result = conn
+            .exec_params(
+                "SELECT length($1)",
+                &[],
+                &[Some(value.into_bytes())],
+                &[],
+                crate::Format::Text,
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.value(0, 0), Some(&b"1048576"[..]));
+
+        let result = conn.exec("SELECT repeat('a', 1024 * 1024)").await.unwrap();
+        assert_eq!(result.value(0, 0).map(<[u8]>::len), Some(1024 * 1024));
+    }
+}
@@ -1,4 +1,4 @@
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub struct Cancel {
     cancel: *mut pq_sys::pg_cancel,
 }
@@ -27,6 +27,11 @@ impl Cancel {
     }
 }
 
+// PQcancel is thread-safe, the cancel object is only read by it.
+unsafe impl Send for Cancel {}
+
+unsafe impl Sync for Cancel {}
+
 #[doc(hidden)]
 impl From<*mut pq_sys::pg_cancel> for Cancel {
     fn from(cancel: *mut pq_sys::pg_cancel) -> Self {
@@ -48,3 +53,227 @@ impl Drop for Cancel {
         }
     }
 }
+
+/**
+ * A connection used to cancel the query in progress on another connection, created by
+ * `libpq::Connection::cancel_connection`.
+ *
+ * Unlike `libpq::connection::Cancel`, it uses the encryption settings of the original connection
+ * and can be driven without blocking: `start`, then `poll` each time `socket` is ready for the
+ * returned status.
+ *
+ * See [Canceling Queries in Progress](https://www.postgresql.org/docs/17/libpq-cancel.html).
+ *
+ * # Examples
+ *
+ * ```
+ * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+ * let conn = libpq::Connection::new(&dsn).unwrap();
+ * let mut cancel = conn.cancel_connection().unwrap();
+ *
+ * conn.send_query("SELECT pg_sleep(10)").unwrap();
+ *
+ * // A request received before the query starts is ignored, it's sent again until the query ends.
+ * while conn.is_busy() {
+ *     cancel.blocking().unwrap();
+ *     cancel.reset();
+ *     std::thread::sleep(std::time::Duration::from_millis(100));
+ *     conn.consume_input().unwrap();
+ * }
+ *
+ * let result = conn.result().unwrap();
+ * # while conn.result().is_some() {}
+ * assert_eq!(result.status(), libpq::Status::FatalError);
+ * ```
+ */
+#[cfg(feature = "v17")]
+#[cfg_attr(docsrs, doc(cfg(feature = "v17")))]
+#[derive(Debug)]
+pub struct CancelConnection {
+    cancel: *mut pq_sys::PGcancelConn,
+}
+
+#[cfg(feature = "v17")]
+impl CancelConnection {
+    pub(crate) fn new(conn: &crate::Connection) -> crate::errors::Result<Self> {
+        let cancel = unsafe { pq_sys::PQcancelCreate(conn.into()) };
+
+        if cancel.is_null() {
+            return Err(crate::errors::Error::Unknow);
+        }
+
+        let cancel = Self { cancel };
+
+        if cancel.status() == crate::connection::Status::Bad {
+            return cancel.error();
+        }
+
+        Ok(cancel)
+    }
+
+    /**
+     * Requests the cancellation and waits for the server to handle it.
+     *
+     * See
+     * [PQcancelBlocking](https://www.postgresql.org/docs/17/libpq-cancel.html#LIBPQ-PQCANCELBLOCKING).
+     */
+    pub fn blocking(&mut self) -> crate::errors::Result {
+        log::trace!("Canceling");
+
+        let success = unsafe { pq_sys::PQcancelBlocking(self.cancel) };
+
+        if success == 1 {
+            Ok(())
+        } else {
+            self.error()
+        }
+    }
+
+    /**
+     * Starts a nonblocking cancellation, it must be completed by `poll`.
+     *
+     * See
+     * [PQcancelStart](https://www.postgresql.org/docs/17/libpq-cancel.html#LIBPQ-PQCANCELSTART).
+     */
+    pub fn start(&mut self) -> crate::errors::Result {
+        log::trace!("Starting cancel");
+
+        let success = unsafe { pq_sys::PQcancelStart(self.cancel) };
+
+        if success == 1 {
+            Ok(())
+        } else {
+            self.error()
+        }
+    }
+
+    /**
+     * Advances a nonblocking cancellation, the request is sent once it returns
+     * `libpq::poll::Status::Ok`.
+     *
+     * See
+     * [PQcancelPoll](https://www.postgresql.org/docs/17/libpq-cancel.html#LIBPQ-PQCANCELPOLL).
+     */
+    pub fn poll(&mut self) -> crate::poll::Status {
+        unsafe { pq_sys::PQcancelPoll(self.cancel) }.into()
+    }
+
+    /**
+     * Returns the status of the cancel connection.
+     *
+     * See
+     * [PQcancelStatus](https://www.postgresql.org/docs/17/libpq-cancel.html#LIBPQ-PQCANCELSTATUS).
+     */
+    pub fn status(&self) -> crate::connection::Status {
+        unsafe { pq_sys::PQcancelStatus(self.cancel) }.into()
+    }
+
+    /**
+     * Returns the socket of the cancel connection, to wait for during `poll`.
+     *
+     * See
+     * [PQcancelSocket](https://www.postgresql.org/docs/17/libpq-cancel.html#LIBPQ-PQCANCELSOCKET).
+     */
+    pub fn socket(&self) -> crate::errors::Result<i32> {
+        let socket = unsafe { pq_sys::PQcancelSocket(self.cancel) };
+
+        if socket < 0 {
+            Err(crate::errors::Error::Unknow)
+        } else {
+            Ok(socket)
+        }
+    }
+
+    /**
+     * Returns the error message of the last failed operation.
+     *
+     * See
+     * [PQcancelErrorMessage](https://www.postgresql.org/docs/17/libpq-cancel.html#LIBPQ-PQCANCELERRORMESSAGE).
+     */
+    pub fn error_message(&self) -> Option<String> {
+        let error = unsafe { pq_sys::PQcancelErrorMessage(self.cancel) };
+
+        if error.is_null() {
+            None
+        } else {
+            let error = unsafe { std::ffi::CStr::from_ptr(error) };
+
+            Some(error.to_string_lossy().trim_end().to_string())
+        }
+    }
+
+    /**
+     * Resets the cancel connection, to reuse it for another cancellation.
+     *
+     * See
+     * [PQcancelReset](https://www.postgresql.org/docs/17/libpq-cancel.html#LIBPQ-PQCANCELRESET).
+     */
+    pub fn reset(&mut self) {
+        unsafe { pq_sys::PQcancelReset(self.cancel) };
+    }
+
+    fn error<T>(&self) -> crate::errors::Result<T> {
+        match self.error_message() {
+            Some(message) if !message.is_empty() => Err(crate::errors::Error::Backend(message)),
+            _ => Err(crate::errors::Error::Unknow),
+        }
+    }
+}
+
+// The cancel connection is independent of the original one, and only modified through `&mut`.
+#[cfg(feature = "v17")]
+unsafe impl Send for CancelConnection {}
+
+#[cfg(feature = "v17")]
+unsafe impl Sync for CancelConnection {}
+
+#[cfg(feature = "v17")]
+impl Drop for CancelConnection {
+    fn drop(&mut self) {
+        unsafe {
+            pq_sys::PQcancelFinish(self.cancel);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "v17"))]
+mod test {
+    #[test]
+    fn cancel_connection() {
+        let conn = crate::test::new_conn();
+        let mut cancel = conn.cancel_connection().unwrap();
+        assert_eq!(cancel.status(), crate::connection::Status::Allocated);
+
+        for _ in 0..2 {
+            conn.send_query("SELECT pg_sleep(10)").unwrap();
+
+            // Retries while the request arrives before the query starts.
+            while conn.is_busy() {
+                cancel.start().unwrap();
+
+                loop {
+                    match cancel.poll() {
+                        crate::poll::Status::Ok => break,
+                        crate::poll::Status::Failed => panic!("{:?}", cancel.error_message()),
+                        _ => std::thread::sleep(std::time::Duration::from_millis(10)),
+                    }
+                }
+
+                cancel.reset();
+                std::thread::sleep(std::time::Duration::from_millis(100));
+                conn.consume_input().unwrap();
+            }
+
+            let error = conn.check(conn.result().unwrap()).unwrap_err();
+            assert_eq!(error.state(), Some(&crate::state::QUERY_CANCELED));
+            while conn.result().is_some() {}
+        }
+    }
+
+    #[test]
+    fn send() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<crate::connection::CancelConnection>();
+    }
+}
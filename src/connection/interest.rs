@@ -0,0 +1,41 @@
+bitflags::bitflags! {
+    /**
+     * Readiness of the socket to wait for before the next step, see
+     * `libpq::Connection::wants`.
+     *
+     * An empty interest means the step can be called again immediately.
+     */
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    pub struct Interest : u8 {
+        /** Wait for the socket to be readable. */
+        const READ = 0b01;
+        /** Wait for the socket to be writable. */
+        const WRITE = 0b10;
+    }
+}
+
+impl From<crate::poll::Status> for Interest {
+    fn from(status: crate::poll::Status) -> Self {
+        match status {
+            crate::poll::Status::Reading | crate::poll::Status::Ok => Self::READ,
+            crate::poll::Status::Writing => Self::WRITE,
+            crate::poll::Status::Failed | crate::poll::Status::Active => Self::empty(),
+        }
+    }
+}
+
+#[cfg(feature = "mio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mio")))]
+impl From<Interest> for Option<mio::Interest> {
+    fn from(interest: Interest) -> Self {
+        match (
+            interest.contains(Interest::READ),
+            interest.contains(Interest::WRITE),
+        ) {
+            (true, true) => Some(mio::Interest::READABLE | mio::Interest::WRITABLE),
+            (true, false) => Some(mio::Interest::READABLE),
+            (false, true) => Some(mio::Interest::WRITABLE),
+            (false, false) => None,
+        }
+    }
+}
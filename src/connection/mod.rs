@@ -1,28 +1,51 @@
+#[cfg(all(feature = "tokio", unix))]
+mod asynchronous;
 mod buffer;
 mod cancel;
+mod config;
 mod info;
+mod interest;
+mod notice;
 mod notify;
+mod param;
+mod shared;
+#[cfg(all(feature = "mio", unix))]
+mod source;
+mod statement;
 mod status;
+mod stream;
 
+#[cfg(all(feature = "tokio", unix))]
+pub use asynchronous::*;
 pub use buffer::*;
 pub use cancel::*;
+pub use config::*;
 pub use info::*;
+pub use interest::*;
+pub use notice::Notice;
 pub use notify::*;
+pub use param::*;
+pub use shared::*;
+pub use statement::*;
 pub use status::*;
+pub use stream::*;
 
 pub type NoticeProcessor = pq_sys::PQnoticeProcessor;
 pub type NoticeReceiver = pq_sys::PQnoticeReceiver;
 
 use std::os::raw;
 
-#[derive(Clone)]
 pub struct Connection {
     conn: *mut pq_sys::PGconn,
+    wants: std::cell::Cell<Interest>,
+    // Boxed, libpq keeps a pointer to it for the notice receiver.
+    notices: notice::Receiver,
 }
 
 unsafe impl Send for Connection {}
 
 include!("_async.rs");
+include!("_batch.rs");
 include!("_cancel.rs");
 include!("_connect.rs");
 include!("_control.rs");
@@ -37,6 +60,7 @@ include!("_ssl.rs");
 include!("_status.rs");
 include!("_threading.rs");
 include!("_trace.rs");
+include!("_transaction.rs");
 
 impl Connection {
     /**
@@ -82,43 +106,26 @@ impl Connection {
     }
 
     fn transform_params(
+        param_types: &[crate::Oid],
         param_values: &[Option<Vec<u8>>],
         param_formats: &[crate::Format],
-    ) -> (Vec<*const raw::c_char>, Vec<i32>, Vec<i32>) {
-        if param_values.is_empty() {
-            return Default::default();
-        }
-
-        let mut values = Vec::new();
-        let mut formats = Vec::new();
-        let mut lengths = Vec::new();
-
-        for (x, value) in param_values.iter().enumerate() {
-            let format = param_formats.get(x).unwrap_or(&crate::Format::Text);
-            formats.push(format.into());
-
-            if let Some(v) = value {
-                if format == &crate::Format::Text && v.last() != Some(&b'\0') {
-                    panic!("Param value as text should be null terminated");
+    ) -> Vec<Param> {
+        param_values
+            .iter()
+            .enumerate()
+            .map(|(x, value)| {
+                let format = *param_formats.get(x).unwrap_or(&crate::Format::Text);
+                let ty = *param_types.get(x).unwrap_or(&crate::oid::INVALID);
+
+                match value {
+                    Some(value) => Param::new(value.clone(), format, ty),
+                    None => Param::null().with_type(ty),
                 }
-                values.push(v.as_ptr() as *const raw::c_char);
-                lengths.push(v.len() as i32);
-            } else {
-                values.push(std::ptr::null());
-                lengths.push(0);
-            }
-        }
-
-        (values, formats, lengths)
+            })
+            .collect()
     }
 
-    fn trace_query(
-        prefix: &str,
-        command: &str,
-        param_types: &[crate::Oid],
-        param_values: &[Option<Vec<u8>>],
-        param_formats: &[crate::Format],
-    ) {
+    fn trace_query(prefix: &str, command: &str, param_types: &[crate::Oid], params: &[Param]) {
         use std::fmt::Write;
 
         if log::log_enabled!(log::Level::Trace) {
@@ -126,18 +133,24 @@ impl Connection {
 
             let mut p = Vec::new();
 
-            for (x, value) in param_values.iter().enumerate() {
-                let v = if let Some(s) = value {
-                    match param_formats.get(x) {
-                        Some(crate::Format::Binary) => format!("{s:?}"),
-                        _ => String::from_utf8(s.to_vec()).unwrap_or_else(|_| "�".to_string()),
+            for (x, param) in params.iter().enumerate() {
+                let v = if let Some(s) = param.value() {
+                    match param.format() {
+                        crate::Format::Binary => format!("{s:?}"),
+                        crate::Format::Text => {
+                            String::from_utf8(s.to_vec()).unwrap_or_else(|_| "�".to_string())
+                        }
                     }
                 } else {
                     "null".to_string()
                 };
                 let default_type = crate::types::UNKNOWN;
-                let t = crate::Type::try_from(*param_types.get(x).unwrap_or(&default_type.oid))
-                    .unwrap_or(default_type);
+                let oid = param_types
+                    .get(x)
+                    .copied()
+                    .or(param.ty())
+                    .unwrap_or(default_type.oid);
+                let t = crate::Type::try_from(oid).unwrap_or(default_type);
 
                 p.push(format!("'{v}'::{}", t.name));
             }
@@ -160,6 +173,22 @@ impl Connection {
             .map(|x| crate::errors::Error::Backend(x.to_string()))
             .unwrap_or(crate::errors::Error::Unknow))
     }
+
+    /**
+     * Turns a failed result into an error, using the connection error message if the result
+     * doesn't carry a SQLSTATE.
+     */
+    pub(crate) fn check(&self, result: crate::PQResult) -> crate::errors::Result<crate::PQResult> {
+        match result.status() {
+            crate::Status::FatalError | crate::Status::BadResponse => {
+                match crate::errors::DbError::from_result(&result) {
+                    Some(error) => Err(error.into()),
+                    None => self.error(),
+                }
+            }
+            _ => Ok(result),
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -188,7 +217,15 @@ impl TryFrom<*mut pq_sys::pg_conn> for Connection {
     type Error = crate::errors::Error;
 
     fn try_from(conn: *mut pq_sys::pg_conn) -> std::result::Result<Self, Self::Error> {
-        let s = Self { conn };
+        let s = Self {
+            conn,
+            wants: std::cell::Cell::new(Interest::WRITE),
+            notices: Default::default(),
+        };
+
+        if s.status() == crate::connection::Status::Ok {
+            s.wants.set(Interest::READ);
+        }
 
         if s.status() == crate::connection::Status::Bad {
             s.error()
@@ -198,6 +235,19 @@ impl TryFrom<*mut pq_sys::pg_conn> for Connection {
     }
 }
 
+#[cfg(unix)]
+impl std::os::unix::io::AsRawFd for Connection {
+    /**
+     * Returns the connection socket, `-1` if there is none.
+     *
+     * The socket may change while connecting or resetting, it must not be cached across these
+     * steps.
+     */
+    fn as_raw_fd(&self) -> std::os::unix::io::RawFd {
+        unsafe { pq_sys::PQsocket(self.into()) }
+    }
+}
+
 impl Drop for Connection {
     fn drop(&mut self) {
         unsafe {
@@ -251,6 +301,282 @@ mod test {
         conn.reset();
     }
 
+    #[test]
+    fn try_clone() {
+        let conn = crate::test::new_conn();
+        let clone = conn.try_clone().unwrap();
+
+        assert_eq!(clone.status(), crate::connection::Status::Ok);
+        assert_ne!(clone.backend_pid(), conn.backend_pid());
+        assert_eq!(clone.db(), conn.db());
+        assert_eq!(clone.user(), conn.user());
+    }
+
+    #[test]
+    fn execute_many() {
+        let conn = crate::test::new_conn();
+        conn.try_exec("CREATE TEMPORARY TABLE tmp (id int PRIMARY KEY, name text)")
+            .unwrap();
+        conn.prepare(Some("batch_insert"), "INSERT INTO tmp VALUES ($1, $2)", &[]);
+        conn.prepare(Some("batch_update"), "UPDATE tmp SET name = $1", &[]);
+
+        let error = conn
+            .execute_many(
+                "batch_insert",
+                &[&[&1, &"one"], &[&2, &None::<&str>], &[&1, &"dup"]],
+            )
+            .unwrap_err();
+        assert!(matches!(error, crate::errors::Error::Batch { row: 2, .. }));
+        assert_eq!(error.state(), Some(&crate::state::UNIQUE_VIOLATION));
+        assert_eq!(conn.try_exec("SELECT * FROM tmp").unwrap().ntuples(), 0);
+
+        let rows = (0..2_500).map(|x| (x, x.to_string())).collect::<Vec<_>>();
+        let params = rows
+            .iter()
+            .map(|(id, name)| [id as &dyn crate::types::ToSql, name])
+            .collect::<Vec<_>>();
+        let params = params.iter().map(|x| &x[..]).collect::<Vec<_>>();
+        let counts = conn.execute_many("batch_insert", &params).unwrap();
+        assert_eq!(counts, vec![1; 2_500]);
+
+        let counts = conn
+            .execute_many("batch_update", &[&[&"it's"], &[&None::<&str>]])
+            .unwrap();
+        assert_eq!(counts, vec![2_500, 2_500]);
+        assert_eq!(conn.execute_many("batch_update", &[]), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn query_stream() {
+        let conn = crate::test::new_conn();
+
+        let values = conn
+            .query_stream("SELECT generate_series(1, $1)", &[&3])
+            .unwrap()
+            .map(|x| x.unwrap().get::<i32>(0, 0).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![1, 2, 3]);
+
+        let mut stream = conn
+            .query_stream("SELECT 1 / (3 - x) FROM generate_series(1, 5) x", &[])
+            .unwrap();
+        assert!(stream.next().unwrap().is_ok());
+        assert!(stream.next().unwrap().is_ok());
+        let error = stream.next().unwrap().unwrap_err();
+        assert_eq!(error.state(), Some(&crate::state::DIVISION_BY_ZERO));
+        assert!(stream.next().is_none());
+        drop(stream);
+
+        let mut stream = conn
+            .query_stream("SET application_name = 'stream'", &[])
+            .unwrap();
+        assert!(stream.next().is_none());
+        drop(stream);
+
+        assert_eq!(conn.try_exec("SELECT 1").unwrap().ntuples(), 1);
+    }
+
+    #[test]
+    fn query_stream_drop() {
+        let conn = crate::test::new_conn();
+
+        let mut stream = conn
+            .query_stream("SELECT generate_series(1, 1000000000)", &[])
+            .unwrap();
+        assert_eq!(stream.next().unwrap().unwrap().ntuples(), 1);
+        drop(stream);
+
+        assert_eq!(conn.transaction_status(), crate::transaction::Status::Idle);
+        assert_eq!(conn.try_exec("SELECT 1").unwrap().ntuples(), 1);
+
+        // Every row read, only the final result is left.
+        let mut stream = conn.query_stream("SELECT 1", &[]).unwrap();
+        assert!(stream.next().unwrap().is_ok());
+        drop(stream);
+
+        assert_eq!(conn.try_exec("SELECT 2").unwrap().get::<i32>(0, 0), Ok(2));
+    }
+
+    #[test]
+    #[cfg(feature = "v17")]
+    fn query_chunks() {
+        let conn = crate::test::new_conn();
+
+        let sizes = conn
+            .query_chunks("SELECT generate_series(1, 10)", &[], 4)
+            .unwrap()
+            .map(|x| {
+                let result = x.unwrap();
+                assert_eq!(result.status(), crate::Status::TuplesChunk);
+                result.ntuples()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, vec![4, 4, 2]);
+
+        assert!(conn.query_chunks("SELECT 1", &[], 0).is_err());
+        assert_eq!(
+            conn.query_chunks("SELECT 1", &[], usize::MAX)
+                .map(|_| ())
+                .unwrap_err(),
+            crate::errors::Error::InvalidOption("chunk_size".to_string())
+        );
+        assert_eq!(conn.try_exec("SELECT 1").unwrap().ntuples(), 1);
+    }
+
+    #[test]
+    fn close_prepared() {
+        let conn = crate::test::new_conn();
+
+        conn.prepare(Some("close \"quoted\""), "SELECT 1", &[]);
+        assert!(conn.close_prepared(Some("close \"quoted\"")).is_ok());
+
+        // Closing an unknown statement is only an error with `DEALLOCATE`.
+        #[cfg(not(feature = "v17"))]
+        assert_eq!(
+            conn.close_prepared(Some("close \"quoted\""))
+                .unwrap_err()
+                .state(),
+            Some(&crate::state::UNDEFINED_PSTATEMENT)
+        );
+        #[cfg(not(feature = "v17"))]
+        assert_eq!(
+            conn.close_prepared(None).unwrap_err(),
+            crate::errors::Error::InvalidOption("name".to_string())
+        );
+
+        conn.prepare(Some("send close"), "SELECT 1", &[]);
+        conn.send_close_prepared(Some("send close")).unwrap();
+        assert_eq!(conn.result().unwrap().status(), crate::Status::CommandOk);
+        assert!(conn.result().is_none());
+        assert_eq!(
+            conn.exec_prepared(Some("send close"), &[], &[], crate::Format::Text)
+                .status(),
+            crate::Status::FatalError
+        );
+    }
+
+    #[test]
+    fn close_portal() {
+        let conn = crate::test::new_conn();
+
+        conn.try_exec("BEGIN").unwrap();
+        conn.try_exec("DECLARE portal CURSOR FOR SELECT 1").unwrap();
+        assert!(conn.close_portal(Some("portal")).is_ok());
+
+        conn.try_exec("DECLARE portal CURSOR FOR SELECT 1").unwrap();
+        conn.send_close_portal(Some("portal")).unwrap();
+        assert!(conn.check(conn.result().unwrap()).is_ok());
+        assert!(conn.result().is_none());
+
+        let error = conn.try_exec("FETCH portal").unwrap_err();
+        assert_eq!(error.state(), Some(&crate::state::UNDEFINED_CURSOR));
+    }
+
+    #[test]
+    fn on_notice() {
+        let conn = crate::test::new_conn();
+        let notices = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let received = notices.clone();
+        conn.on_notice(move |notice| received.lock().unwrap().push(notice));
+
+        conn.try_exec(
+            "DO $$ BEGIN RAISE WARNING 'careful' USING DETAIL = 'detail', HINT = 'hint'; END $$",
+        )
+        .unwrap();
+
+        let notices = notices.lock().unwrap();
+        assert_eq!(notices.len(), 1);
+
+        let notice = &notices[0];
+        assert_eq!(notice.severity, "WARNING");
+        assert_eq!(notice.code, "01000");
+        assert_eq!(notice.state, crate::state::WARNING);
+        assert_eq!(notice.message, "careful");
+        assert_eq!(notice.detail.as_deref(), Some("detail"));
+        assert_eq!(notice.hint.as_deref(), Some("hint"));
+        assert!(notice.context.is_some());
+        assert_eq!(notice.level(), log::Level::Warn);
+        assert_eq!(notice.to_string(), "WARNING: careful");
+    }
+
+    #[test]
+    fn internal_notice() {
+        let conn = crate::test::new_conn();
+        let notices = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let received = notices.clone();
+        conn.on_notice(move |notice| received.lock().unwrap().push(notice));
+
+        let result = conn.exec("SELECT 1");
+        assert_eq!(result.field_name(10), Ok(None));
+        {
+            let notices = notices.lock().unwrap();
+            assert_eq!(notices.len(), 1);
+            assert_eq!(notices[0].code, "");
+            assert_eq!(notices[0].state, crate::state::UNKNOWN);
+            assert!(notices[0].message.contains("out of range"));
+        }
+
+        // The result keeps the receiver of a closed connection.
+        drop(conn);
+        std::thread::spawn(move || assert_eq!(result.field_name(10), Ok(None)))
+            .join()
+            .unwrap();
+        assert_eq!(notices.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn log_notices() {
+        let conn = crate::test::new_conn();
+        conn.log_notices();
+
+        conn.try_exec("DO $$ BEGIN RAISE NOTICE 'logged'; END $$")
+            .unwrap();
+    }
+
+    #[test]
+    fn collect_notices() {
+        let conn = crate::test::new_conn();
+        let received = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+        let counter = received.clone();
+        conn.on_notice(move |_| {
+            counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        });
+
+        let (result, notices) = conn
+            .collect_notices(|conn| conn.try_exec("SELECT max(x) FROM (VALUES (1), (NULL)) v(x)"));
+        assert!(result.is_ok());
+        assert!(notices.is_empty());
+
+        let (outer, notices) = conn.collect_notices(|conn| {
+            conn.try_exec("DO $$ BEGIN RAISE NOTICE 'outer'; END $$")
+                .unwrap();
+
+            let (_, notices) = conn.collect_notices(|conn| {
+                conn.try_exec("DO $$ BEGIN RAISE WARNING 'inner'; END $$")
+                    .unwrap();
+            });
+
+            notices
+        });
+        assert_eq!(outer.len(), 1);
+        assert_eq!(outer[0].message, "inner");
+        assert_eq!(
+            notices
+                .iter()
+                .map(|x| x.message.as_str())
+                .collect::<Vec<_>>(),
+            ["outer", "inner"]
+        );
+        assert_eq!(received.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        conn.try_exec("DO $$ BEGIN RAISE NOTICE 'after'; END $$")
+            .unwrap();
+        assert_eq!(received.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
     #[test]
     fn poll() {
         let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
@@ -260,6 +586,89 @@ mod test {
         assert_eq!(conn.reset_poll(), crate::poll::Status::Writing);
     }
 
+    #[cfg(unix)]
+    fn wait(conn: &crate::Connection) {
+        use std::os::unix::io::AsRawFd;
+
+        let wants = conn.wants();
+        let mut events = 0;
+
+        if wants.contains(crate::connection::Interest::READ) {
+            events |= libc::POLLIN;
+        }
+        if wants.contains(crate::connection::Interest::WRITE) {
+            events |= libc::POLLOUT;
+        }
+
+        let mut fd = libc::pollfd {
+            fd: conn.as_raw_fd(),
+            events,
+            revents: 0,
+        };
+
+        assert!(unsafe { libc::poll(&mut fd, 1, 10_000) } > 0);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn wants() {
+        let conn = crate::Connection::start(&crate::test::dsn()).unwrap();
+        assert_eq!(conn.wants(), crate::connection::Interest::WRITE);
+
+        loop {
+            wait(&conn);
+
+            match conn.poll() {
+                crate::poll::Status::Ok => break,
+                crate::poll::Status::Failed => panic!("{:?}", conn.error_message()),
+                _ => (),
+            }
+        }
+        assert_eq!(conn.wants(), crate::connection::Interest::READ);
+
+        conn.set_non_blocking(true).unwrap();
+        conn.send_query("SELECT 1").unwrap();
+
+        while conn.flush().is_err() {
+            assert!(conn.wants().contains(crate::connection::Interest::WRITE));
+            wait(&conn);
+        }
+        assert_eq!(conn.wants(), crate::connection::Interest::READ);
+
+        while conn.is_busy() {
+            wait(&conn);
+            conn.consume_input().unwrap();
+            assert_eq!(conn.wants(), crate::connection::Interest::READ);
+        }
+
+        assert_eq!(conn.result().unwrap().value(0, 0), Some(&b"1"[..]));
+    }
+
+    #[test]
+    #[cfg(all(feature = "mio", unix))]
+    fn mio() {
+        let mut conn = crate::test::new_conn();
+        let mut poll = mio::Poll::new().unwrap();
+        let mut events = mio::Events::with_capacity(1);
+
+        let interest: Option<mio::Interest> = conn.wants().into();
+        poll.registry()
+            .register(&mut conn, mio::Token(0), interest.unwrap())
+            .unwrap();
+
+        conn.send_query("SELECT 1").unwrap();
+
+        while conn.is_busy() {
+            poll.poll(&mut events, Some(std::time::Duration::from_secs(10)))
+                .unwrap();
+            assert!(!events.is_empty());
+            conn.consume_input().unwrap();
+        }
+
+        assert_eq!(conn.result().unwrap().value(0, 0), Some(&b"1"[..]));
+        poll.registry().deregister(&mut conn).unwrap();
+    }
+
     #[test]
     fn exec() {
         let conn = crate::test::new_conn();
@@ -309,16 +718,268 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
     fn exec_text() {
         let conn = crate::test::new_conn();
-        let _ = conn.exec_params(
+        let results = conn.exec_params(
             "SELECT $1",
             &[],
             &[Some(b"foo".to_vec())],
             &[],
             crate::Format::Text,
         );
+
+        assert_eq!(results.value(0, 0), Some(&b"foo"[..]));
+    }
+
+    #[test]
+    fn exec_with() {
+        let conn = crate::test::new_conn();
+        let params = [
+            crate::connection::Param::from("foo"),
+            crate::connection::Param::from(String::from("1")).with_type(crate::types::INT4.oid),
+            crate::connection::Param::from(&b"\0\x01"[..]),
+            crate::connection::Param::from(None::<&str>),
+        ];
+
+        let results = conn
+            .exec_with("SELECT $1, $2, $3, $4", &params, crate::Format::Binary)
+            .unwrap();
+        assert_eq!(results.status(), crate::Status::TuplesOk);
+        assert_eq!(results.get::<&str>(0, 0), Ok("foo"));
+        assert_eq!(results.get::<i32>(0, 1), Ok(1));
+        assert_eq!(results.get::<&[u8]>(0, 2), Ok(&b"\0\x01"[..]));
+        assert_eq!(results.get::<Option<String>>(0, 3), Ok(None));
+        assert_eq!(
+            results.get::<Option<String>>(0, 4),
+            Err(crate::errors::Error::ColumnNotFound("4".to_string()))
+        );
+
+        let params = [crate::connection::Param::from("f\0o")];
+        assert!(conn
+            .exec_with("SELECT $1", &params, crate::Format::Text)
+            .is_err());
+    }
+
+    #[test]
+    fn send_query_with() {
+        let conn = crate::test::new_conn();
+        conn.prepare(Some("with"), "SELECT $1::text", &[]);
+
+        conn.send_query_with(
+            "SELECT $1",
+            &[crate::connection::Param::from("foo")],
+            crate::Format::Text,
+        )
+        .unwrap();
+        assert_eq!(conn.result().unwrap().value(0, 0), Some(&b"foo"[..]));
+        assert!(conn.result().is_none());
+
+        let result = conn
+            .exec_prepared_with(
+                Some("with"),
+                &[crate::connection::Param::from("bar")],
+                crate::Format::Text,
+            )
+            .unwrap();
+        assert_eq!(result.value(0, 0), Some(&b"bar"[..]));
+
+        conn.send_query_prepared_with(
+            Some("with"),
+            &[crate::connection::Param::from("baz")],
+            crate::Format::Text,
+        )
+        .unwrap();
+        assert_eq!(conn.result().unwrap().value(0, 0), Some(&b"baz"[..]));
+        assert!(conn.result().is_none());
+    }
+
+    #[test]
+    fn rows() {
+        let conn = crate::test::new_conn();
+        let results = conn.exec("SELECT x AS id, 'foo' || x AS name FROM generate_series(1, 3) x");
+
+        let rows = results.rows();
+        assert_eq!(rows.len(), 3);
+
+        for (x, row) in rows.enumerate() {
+            assert_eq!(row.number(), x);
+            assert_eq!(row.len(), 2);
+            assert_eq!(row.value(0), Ok(Some(format!("{}", x + 1).as_bytes())));
+            assert_eq!(row.get::<String, _>("name"), Ok(format!("foo{}", x + 1)));
+        }
+
+        let row = results.row(0).unwrap();
+        assert_eq!(row.get::<i32, _>("id"), Ok(1));
+        assert_eq!(
+            row.get::<i32, _>("ID"),
+            Err(crate::errors::Error::ColumnNotFound("ID".to_string()))
+        );
+        assert_eq!(
+            row.is_null(2),
+            Err(crate::errors::Error::ColumnNotFound("2".to_string()))
+        );
+        assert!(results.row(3).is_none());
+        assert_eq!(
+            (&results).into_iter().next_back().map(|x| x.number()),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn columns() {
+        let conn = crate::test::new_conn();
+        conn.exec("CREATE TEMPORARY TABLE tmp_columns (id int4, name varchar(10))");
+        let results = conn.exec("SELECT id, name, 1::int8 AS one FROM tmp_columns");
+
+        let columns = results.columns().unwrap();
+        assert_eq!(columns.len(), 3);
+
+        assert_eq!(columns[0].name, "id");
+        assert_eq!(columns[0].ty(), crate::types::INT4);
+        assert!(columns[0].table.is_some());
+        assert_eq!(columns[0].tablecol, 1);
+        assert_eq!(columns[0].size, Some(4));
+
+        assert_eq!(columns[1].ty(), crate::types::VARCHAR);
+        assert_eq!(columns[1].tablecol, 2);
+        assert_eq!(columns[1].size, None);
+        assert!(columns[1].modifier.is_some());
+
+        assert_eq!(columns[2].name, "one");
+        assert_eq!(columns[2].number, 2);
+        assert_eq!(columns[2].table, None);
+        assert_eq!(columns[2].format, crate::Format::Text);
+    }
+
+    #[test]
+    fn exec_typed() {
+        let conn = crate::test::new_conn();
+
+        for format in [crate::Format::Text, crate::Format::Binary] {
+            let results = conn
+                .exec_typed(
+                    "SELECT $1, $2, $3, $4, $5",
+                    &[&true, &42_i64, &1.5_f64, &"foo", &None::<i32>],
+                    format,
+                )
+                .unwrap();
+            assert_eq!(results.status(), crate::Status::TuplesOk);
+
+            assert_eq!(results.get::<bool>(0, 0), Ok(true));
+            assert_eq!(results.get::<i64>(0, 1), Ok(42));
+            assert_eq!(results.get::<f64>(0, 2), Ok(1.5));
+            assert_eq!(results.get::<&str>(0, 3), Ok("foo"));
+            assert_eq!(results.get::<Option<i32>>(0, 4), Ok(None));
+            assert_eq!(
+                results.get::<i32>(0, 4),
+                Err(crate::errors::Error::UnexpectedNull)
+            );
+        }
+    }
+
+    #[test]
+    fn exec_typed_bytea() {
+        let conn = crate::test::new_conn();
+        let data = b"\0\x01\xff".to_vec();
+
+        for format in [crate::Format::Text, crate::Format::Binary] {
+            let results = conn.exec_typed("SELECT $1", &[&data], format).unwrap();
+
+            assert_eq!(results.get::<Vec<u8>>(0, 0), Ok(data.clone()));
+        }
+    }
+
+    #[test]
+    fn exec_prepared_typed() {
+        let conn = crate::test::new_conn();
+        conn.prepare(
+            Some("typed"),
+            "SELECT $1::int4 + 1",
+            &[crate::types::INT4.oid],
+        );
+
+        let results = conn
+            .exec_prepared_typed(Some("typed"), &[&41_i32], crate::Format::Binary)
+            .unwrap();
+        assert_eq!(results.get::<i32>(0, 0), Ok(42));
+    }
+
+    #[test]
+    fn try_exec() {
+        let conn = crate::test::new_conn();
+
+        let result = conn.try_exec("SELECT 1").unwrap();
+        assert_eq!(result.status(), crate::Status::TuplesOk);
+
+        let error = conn.try_exec("SELECT 1 FORM t").unwrap_err();
+        assert_eq!(error.state(), Some(&crate::state::SYNTAX_ERROR));
+
+        let error = error.db_error().unwrap();
+        assert_eq!(error.severity_nonlocalized.as_deref(), Some("ERROR"));
+        assert_eq!(error.position, Some(15));
+        assert!(error.file.is_some());
+        assert!(error.line.is_some());
+    }
+
+    #[test]
+    fn try_exec_params() {
+        let conn = crate::test::new_conn();
+        conn.try_exec("CREATE TEMPORARY TABLE unique_test (id int CONSTRAINT unique_id UNIQUE)")
+            .unwrap();
+        conn.try_exec_params(
+            "INSERT INTO unique_test VALUES ($1)",
+            &[],
+            &[Some(b"1".to_vec())],
+            &[],
+            crate::Format::Text,
+        )
+        .unwrap();
+
+        let error = conn
+            .try_exec_params(
+                "INSERT INTO unique_test VALUES ($1)",
+                &[],
+                &[Some(b"1".to_vec())],
+                &[],
+                crate::Format::Text,
+            )
+            .unwrap_err();
+        let error = error.db_error().unwrap();
+        assert_eq!(error.state, crate::state::UNIQUE_VIOLATION);
+        assert_eq!(error.table.as_deref(), Some("unique_test"));
+        assert_eq!(error.constraint.as_deref(), Some("unique_id"));
+        assert!(error.detail.is_some());
+    }
+
+    #[test]
+    fn try_exec_prepared() {
+        let conn = crate::test::new_conn();
+        conn.prepare(Some("try_exec_prepared"), "SELECT 1 / $1::int", &[]);
+
+        let result = conn
+            .try_exec_prepared(
+                Some("try_exec_prepared"),
+                &[Some(b"1".to_vec())],
+                &[],
+                crate::Format::Text,
+            )
+            .unwrap();
+        assert_eq!(result.value(0, 0), Some(&b"1"[..]));
+
+        let error = conn
+            .try_exec_prepared(
+                Some("try_exec_prepared"),
+                &[Some(b"0".to_vec())],
+                &[],
+                crate::Format::Text,
+            )
+            .unwrap_err();
+        assert_eq!(error.state(), Some(&crate::state::DIVISION_BY_ZERO));
+
+        let error = conn
+            .try_exec_prepared(Some("unknown"), &[], &[], crate::Format::Text)
+            .unwrap_err();
+        assert_eq!(error.state(), Some(&crate::state::UNDEFINED_PSTATEMENT));
     }
 
     #[test]
@@ -0,0 +1,231 @@
+/**
+ * A notice or warning message sent by the server.
+ *
+ * See [Notice Processing](https://www.postgresql.org/docs/current/libpq-notice-processing.html).
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Notice {
+    /** Localized severity, e.g. `NOTICE` or `WARNING`. */
+    pub severity: String,
+    /** Nonlocalized severity, sent by servers from 9.6. */
+    pub severity_nonlocalized: Option<String>,
+    /** The raw SQLSTATE code. */
+    pub code: String,
+    /** The SQLSTATE. */
+    pub state: crate::State,
+    /** The primary human-readable message. */
+    pub message: String,
+    /** An optional secondary message carrying more detail. */
+    pub detail: Option<String>,
+    /** An optional suggestion what to do about the problem. */
+    pub hint: Option<String>,
+    /** An indication of the context in which the notice was raised. */
+    pub context: Option<String>,
+}
+
+impl Notice {
+    /**
+     * Extracts the notice fields of a result.
+     *
+     * Notices generated by libpq itself don't carry a SQLSTATE, their `code` is empty and their
+     * state is `libpq::state::UNKNOWN`.
+     */
+    pub fn from_result(result: &crate::PQResult) -> Self {
+        use crate::result::ErrorField;
+
+        if let Some(error) = crate::errors::DbError::from_result(result) {
+            return error.into();
+        }
+
+        let field = |field| {
+            result
+                .error_field(field)
+                .ok()
+                .flatten()
+                .map(ToString::to_string)
+        };
+
+        Self {
+            severity: field(ErrorField::Severity).unwrap_or_else(|| "NOTICE".to_string()),
+            severity_nonlocalized: field(ErrorField::SeverityNonlocalized),
+            code: String::new(),
+            state: crate::state::UNKNOWN,
+            message: field(ErrorField::MessagePrimary).unwrap_or_default(),
+            detail: None,
+            hint: None,
+            context: None,
+        }
+    }
+
+    /**
+     * Returns the log level matching the severity.
+     */
+    pub fn level(&self) -> log::Level {
+        let severity = self
+            .severity_nonlocalized
+            .as_deref()
+            .unwrap_or(&self.severity);
+
+        match severity {
+            "DEBUG" => log::Level::Debug,
+            "LOG" | "INFO" | "NOTICE" => log::Level::Info,
+            "WARNING" => log::Level::Warn,
+            _ => log::Level::Error,
+        }
+    }
+}
+
+impl From<crate::errors::DbError> for Notice {
+    fn from(error: crate::errors::DbError) -> Self {
+        Self {
+            severity: error.severity,
+            severity_nonlocalized: error.severity_nonlocalized,
+            code: error.code,
+            state: error.state,
+            message: error.message,
+            detail: error.detail,
+            hint: error.hint,
+            context: error.context,
+        }
+    }
+}
+
+impl std::fmt::Display for Notice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.severity, self.message)
+    }
+}
+
+pub(crate) type NoticeHandler = dyn FnMut(Notice) + Send;
+
+/**
+ * State shared with the libpq notice receiver, owned by the connection.
+ */
+pub(crate) struct Notices {
+    /** `true` while `receive` is the connection notice receiver. */
+    pub installed: bool,
+    pub handler: Option<Box<NoticeHandler>>,
+    /** Notices of the current `libpq::Connection::collect_notices` call. */
+    pub collected: Option<Vec<Notice>>,
+    /** Receiver replaced by `receive`, called when there is no handler. */
+    pub fallback: (crate::connection::NoticeReceiver, *mut std::ffi::c_void),
+}
+
+// The fallback argument is given by the caller of the unsafe `set_notice_receiver`.
+unsafe impl Send for Notices {}
+
+impl Default for Notices {
+    fn default() -> Self {
+        Self {
+            installed: false,
+            handler: None,
+            collected: None,
+            fallback: (None, std::ptr::null_mut()),
+        }
+    }
+}
+
+type Shared = std::sync::Arc<std::sync::Mutex<Notices>>;
+
+/**
+ * Notices of the living connections, by id.
+ *
+ * libpq copies the notice receiver and its argument into every result, which can outlive the
+ * connection and move to another thread: the argument is an id looked up here, not a pointer.
+ */
+static REGISTRY: std::sync::Mutex<std::collections::BTreeMap<usize, Shared>> =
+    std::sync::Mutex::new(std::collections::BTreeMap::new());
+
+static NEXT_ID: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(1);
+
+fn lock<T>(mutex: &std::sync::Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/**
+ * The notices of a connection, registered while `receive` may be called for it.
+ */
+pub(crate) struct Receiver {
+    id: usize,
+    notices: Shared,
+}
+
+impl Default for Receiver {
+    fn default() -> Self {
+        Self {
+            id: NEXT_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed),
+            notices: Default::default(),
+        }
+    }
+}
+
+impl Receiver {
+    pub fn lock(&self) -> std::sync::MutexGuard<'_, Notices> {
+        lock(&self.notices)
+    }
+
+    /**
+     * Registers the notices, and returns the argument to give to `receive`.
+     */
+    pub fn register(&self) -> *mut std::ffi::c_void {
+        lock(&REGISTRY).insert(self.id, self.notices.clone());
+
+        self.id as *mut std::ffi::c_void
+    }
+}
+
+impl Drop for Receiver {
+    fn drop(&mut self) {
+        lock(&REGISTRY).remove(&self.id);
+    }
+}
+
+fn dispatch(notices: &std::sync::Mutex<Notices>, notice: Notice, result: *const pq_sys::PGresult) {
+    // The handler is taken out to be called unlocked: a notice raised meanwhile, by the handler
+    // itself or by another thread, goes to the fallback receiver.
+    let (handler, fallback) = {
+        let mut notices = lock(notices);
+
+        if let Some(collected) = &mut notices.collected {
+            collected.push(notice.clone());
+        }
+
+        (notices.handler.take(), notices.fallback)
+    };
+
+    if let Some(mut handler) = handler {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| handler(notice)));
+
+        if result.is_err() {
+            log::error!("Notice handler panicked");
+        }
+
+        let mut notices = lock(notices);
+
+        // Unless replaced or removed meanwhile.
+        if notices.installed && notices.handler.is_none() {
+            notices.handler = Some(handler);
+        }
+    } else if let (Some(fallback), arg) = fallback {
+        unsafe { fallback(arg, result) };
+    }
+}
+
+/**
+ * Notice receiver installed by `libpq::Connection::on_notice` and
+ * `libpq::Connection::collect_notices`, `arg` is the id of the connection `Receiver`.
+ */
+pub(crate) unsafe extern "C" fn receive(arg: *mut std::ffi::c_void, raw: *const pq_sys::PGresult) {
+    // The result is owned by libpq, it must not be cleared.
+    let result = std::mem::ManuallyDrop::new(crate::PQResult::from(raw as *mut pq_sys::PGresult));
+    let notice = Notice::from_result(&result);
+
+    let notices = lock(&REGISTRY).get(&(arg as usize)).cloned();
+
+    match notices {
+        Some(notices) => dispatch(&notices, notice, raw),
+        None => log::debug!("Notice received after its connection was closed: {notice}"),
+    }
+}
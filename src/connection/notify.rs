@@ -1,4 +1,3 @@
-#[derive(Clone)]
 pub struct Notify {
     notify: *mut pq_sys::pgNotify,
 }
@@ -26,6 +25,10 @@ impl Notify {
     }
 }
 
+unsafe impl Send for Notify {}
+
+unsafe impl Sync for Notify {}
+
 #[doc(hidden)]
 impl From<*mut pq_sys::pgNotify> for Notify {
     fn from(notify: *mut pq_sys::pgNotify) -> Self {
@@ -58,3 +61,28 @@ impl std::fmt::Debug for Notify {
             .finish()
     }
 }
+
+/**
+ * An owned asynchronous notification.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Notification {
+    /** The notification channel name. */
+    pub channel: String,
+    /** The notification payload, empty if none was given. */
+    pub payload: String,
+    /** Process ID of the notifying server process. */
+    pub pid: u32,
+}
+
+impl TryFrom<Notify> for Notification {
+    type Error = crate::errors::Error;
+
+    fn try_from(notify: Notify) -> Result<Self, Self::Error> {
+        Ok(Self {
+            channel: notify.relname()?,
+            payload: notify.extra()?,
+            pid: notify.be_pid(),
+        })
+    }
+}
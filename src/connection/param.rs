@@ -0,0 +1,336 @@
+use std::os::raw;
+
+/**
+ * A query parameter, sent with `libpq::Connection::exec_with` and friends.
+ *
+ * The value is stored in a buffer owned by the parameter, text values are always null
+ * terminated as expected by libpq.
+ *
+ * # Examples
+ *
+ * ```
+ * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+ * let conn = libpq::Connection::new(&dsn).unwrap();
+ *
+ * let params = [
+ *     libpq::connection::Param::from("foo"),
+ *     libpq::connection::Param::from(None::<&str>),
+ * ];
+ * let result = conn
+ *     .exec_with("SELECT $1, $2", &params, libpq::Format::Text)
+ *     .unwrap();
+ *
+ * assert_eq!(result.value(0, 0), Some(&b"foo"[..]));
+ * assert_eq!(result.value(0, 1), None);
+ * ```
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Param {
+    value: Option<Vec<u8>>,
+    format: crate::Format,
+    ty: crate::Oid,
+}
+
+impl Param {
+    /**
+     * Creates a text parameter, the type is inferred by the server.
+     */
+    pub fn text<S: Into<String>>(value: S) -> Self {
+        let mut value = value.into().into_bytes();
+        value.push(b'\0');
+
+        Self {
+            value: Some(value),
+            format: crate::Format::Text,
+            ty: crate::oid::INVALID,
+        }
+    }
+
+    /**
+     * Creates a binary parameter of the given type.
+     */
+    pub fn binary(value: &[u8], ty: crate::Oid) -> Self {
+        Self {
+            value: Some(value.to_vec()),
+            format: crate::Format::Binary,
+            ty,
+        }
+    }
+
+    /**
+     * Creates a `NULL` parameter.
+     */
+    pub fn null() -> Self {
+        Self {
+            value: None,
+            format: crate::Format::Text,
+            ty: crate::oid::INVALID,
+        }
+    }
+
+    /**
+     * Creates a parameter from a rust value, see [`crate::types::ToSql`].
+     *
+     * A text value containing a null byte is a `Conversion` error, libpq would truncate it.
+     */
+    pub fn from_sql(value: &dyn crate::types::ToSql) -> crate::errors::Result<Self> {
+        let format = value.format();
+        let ty = value.ty().oid;
+
+        let param = match value.to_sql(format)? {
+            Some(value) if format == crate::Format::Text && value.contains(&b'\0') => {
+                return Err(crate::errors::Error::Conversion(
+                    "text parameter contains a null byte".to_string(),
+                ));
+            }
+            Some(value) => Self::new(value, format, ty),
+            None => Self::null().with_type(ty),
+        };
+
+        Ok(param)
+    }
+
+    /**
+     * Creates a parameter from a buffer, as accepted by `libpq::Connection::exec_params`.
+     *
+     * Like libpq, a text buffer ends at its first null byte, it is null terminated if it doesn't
+     * contain any. This truncation is kept for the raw buffers of `exec_params` only.
+     */
+    pub(crate) fn new(mut value: Vec<u8>, format: crate::Format, ty: crate::Oid) -> Self {
+        if format == crate::Format::Text {
+            match value.iter().position(|x| *x == b'\0') {
+                Some(end) => value.truncate(end + 1),
+                None => value.push(b'\0'),
+            }
+        }
+
+        Self {
+            value: Some(value),
+            format,
+            ty,
+        }
+    }
+
+    /**
+     * Sets the parameter type.
+     */
+    pub fn with_type(mut self, ty: crate::Oid) -> Self {
+        self.ty = ty;
+        self
+    }
+
+    /**
+     * Returns the parameter value, without the null terminator for text parameters.
+     */
+    pub fn value(&self) -> Option<&[u8]> {
+        self.value.as_deref().map(|x| match self.format {
+            crate::Format::Text => &x[..x.len() - 1],
+            crate::Format::Binary => x,
+        })
+    }
+
+    /**
+     * Returns the parameter format.
+     */
+    pub fn format(&self) -> crate::Format {
+        self.format
+    }
+
+    /**
+     * Returns the parameter type, `None` if it is inferred by the server.
+     */
+    pub fn ty(&self) -> Option<crate::Oid> {
+        if self.ty == crate::oid::INVALID {
+            None
+        } else {
+            Some(self.ty)
+        }
+    }
+}
+
+impl From<&str> for Param {
+    fn from(value: &str) -> Self {
+        Self::text(value)
+    }
+}
+
+impl From<String> for Param {
+    fn from(value: String) -> Self {
+        Self::text(value)
+    }
+}
+
+impl From<&String> for Param {
+    fn from(value: &String) -> Self {
+        Self::text(value.as_str())
+    }
+}
+
+impl From<&[u8]> for Param {
+    fn from(value: &[u8]) -> Self {
+        Self::binary(value, crate::types::BYTEA.oid)
+    }
+}
+
+impl From<Vec<u8>> for Param {
+    fn from(value: Vec<u8>) -> Self {
+        Self::new(value, crate::Format::Binary, crate::types::BYTEA.oid)
+    }
+}
+
+impl<T: Into<Param>> From<Option<T>> for Param {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or_else(Self::null)
+    }
+}
+
+/**
+ * Parameters as arrays of pointers, expected by libpq functions.
+ *
+ * Pointers borrow the buffers of the `Param` slice used to build it.
+ */
+pub(crate) struct RawParams<'a> {
+    types: Vec<crate::Oid>,
+    values: Vec<*const raw::c_char>,
+    lengths: Vec<i32>,
+    formats: Vec<i32>,
+    _params: std::marker::PhantomData<&'a [Param]>,
+}
+
+impl<'a> RawParams<'a> {
+    pub fn new(params: &'a [Param]) -> crate::errors::Result<Self> {
+        let mut raw = Self {
+            types: Vec::new(),
+            values: Vec::new(),
+            lengths: Vec::new(),
+            formats: Vec::new(),
+            _params: std::marker::PhantomData,
+        };
+
+        for param in params {
+            raw.types.push(param.ty);
+            raw.formats.push((&param.format).into());
+
+            match param.value() {
+                Some(value) => {
+                    if param.format == crate::Format::Text && value.contains(&b'\0') {
+                        return Err(crate::errors::Error::Conversion(
+                            "text parameter contains a null byte".to_string(),
+                        ));
+                    }
+
+                    raw.values
+                        .push(param.value.as_ref().unwrap().as_ptr() as *const raw::c_char);
+                    raw.lengths.push(value.len() as i32);
+                }
+                None => {
+                    raw.values.push(std::ptr::null());
+                    raw.lengths.push(0);
+                }
+            }
+        }
+
+        Ok(raw)
+    }
+
+    pub fn len(&self) -> i32 {
+        self.values.len() as i32
+    }
+
+    pub fn types(&self) -> *const crate::Oid {
+        Self::as_ptr(&self.types)
+    }
+
+    pub fn values(&self) -> *const *const raw::c_char {
+        Self::as_ptr(&self.values)
+    }
+
+    pub fn lengths(&self) -> *const i32 {
+        Self::as_ptr(&self.lengths)
+    }
+
+    pub fn formats(&self) -> *const i32 {
+        Self::as_ptr(&self.formats)
+    }
+
+    fn as_ptr<T>(v: &[T]) -> *const T {
+        if v.is_empty() {
+            std::ptr::null()
+        } else {
+            v.as_ptr()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Param;
+
+    #[test]
+    fn text() {
+        let param = Param::from("foo");
+
+        assert_eq!(param.value(), Some(&b"foo"[..]));
+        assert_eq!(param.format(), crate::Format::Text);
+        assert_eq!(param.ty(), None);
+    }
+
+    #[test]
+    fn binary() {
+        let param = Param::from(&b"\0\x01"[..]);
+
+        assert_eq!(param.value(), Some(&b"\0\x01"[..]));
+        assert_eq!(param.format(), crate::Format::Binary);
+        assert_eq!(param.ty(), Some(crate::types::BYTEA.oid));
+    }
+
+    #[test]
+    fn option() {
+        assert_eq!(Param::from(None::<String>), Param::null());
+        assert_eq!(Param::from(Some("foo")), Param::from("foo"));
+    }
+
+    #[test]
+    fn null_terminated() {
+        let param = Param::new(b"foo\0".to_vec(), crate::Format::Text, 0);
+        assert_eq!(param.value(), Some(&b"foo"[..]));
+
+        let param = Param::new(b"foo".to_vec(), crate::Format::Text, 0);
+        assert_eq!(param.value(), Some(&b"foo"[..]));
+
+        let param = Param::new(b"f\0o".to_vec(), crate::Format::Text, 0);
+        assert_eq!(param.value(), Some(&b"f"[..]));
+    }
+
+    #[test]
+    fn invalid() {
+        let params = [Param::from("f\0o")];
+
+        assert!(super::RawParams::new(&params).is_err());
+        assert!(matches!(
+            Param::from_sql(&"f\0o"),
+            Err(crate::errors::Error::Conversion(_))
+        ));
+        assert!(Param::from_sql(&b"f\0o".to_vec()).is_ok());
+    }
+
+    #[test]
+    fn exec_typed_null_byte() {
+        let conn = crate::test::new_conn();
+
+        assert!(matches!(
+            conn.exec_typed("SELECT $1", &[&"a\0b"], crate::Format::Text),
+            Err(crate::errors::Error::Conversion(_))
+        ));
+
+        // The legacy `exec_params` keeps libpq behavior.
+        let result = conn.exec_params(
+            "SELECT $1",
+            &[],
+            &[Some(b"a\0b".to_vec())],
+            &[],
+            crate::Format::Text,
+        );
+        assert_eq!(result.value(0, 0), Some(&b"a"[..]));
+    }
+}
@@ -0,0 +1,111 @@
+/**
+ * A connection shared between threads.
+ *
+ * libpq connections can't be used concurrently, each user locks the connection for the duration
+ * of its commands. Clones share the same session.
+ *
+ * # Examples
+ *
+ * ```
+ * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+ * let conn = libpq::Connection::new(&dsn).unwrap();
+ * let shared = libpq::connection::SharedConnection::new(conn);
+ *
+ * let other = shared.clone();
+ * std::thread::spawn(move || {
+ *     other.lock().try_exec("SELECT 1").unwrap();
+ * })
+ * .join()
+ * .unwrap();
+ * ```
+ */
+#[derive(Clone, Debug)]
+pub struct SharedConnection {
+    inner: std::sync::Arc<std::sync::Mutex<crate::Connection>>,
+    cancel: std::sync::Arc<crate::connection::Cancel>,
+}
+
+impl SharedConnection {
+    /**
+     * Wraps a connection, its cancel object is created once for all clones.
+     */
+    pub fn new(conn: crate::Connection) -> Self {
+        Self {
+            cancel: std::sync::Arc::new(conn.cancel()),
+            inner: std::sync::Arc::new(std::sync::Mutex::new(conn)),
+        }
+    }
+
+    /**
+     * Locks the connection, blocking until it is available.
+     *
+     * A poisoned lock is recovered, the connection state is checked by libpq on the next command.
+     */
+    pub fn lock(&self) -> std::sync::MutexGuard<'_, crate::Connection> {
+        self.inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /**
+     * Requests the cancellation of the command in progress, without locking the connection.
+     *
+     * See `libpq::connection::Cancel::request`.
+     */
+    pub fn cancel(&self) -> crate::errors::Result {
+        self.cancel.request()
+    }
+
+    /**
+     * Returns the connection if this is the last handle.
+     */
+    pub fn into_inner(self) -> Result<crate::Connection, Self> {
+        let Self { inner, cancel } = self;
+
+        match std::sync::Arc::try_unwrap(inner) {
+            Ok(inner) => Ok(inner
+                .into_inner()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)),
+            Err(inner) => Err(Self { inner, cancel }),
+        }
+    }
+}
+
+impl From<crate::Connection> for SharedConnection {
+    fn from(conn: crate::Connection) -> Self {
+        Self::new(conn)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn shared() {
+        let shared = super::SharedConnection::new(crate::test::new_conn());
+        let pid = shared.lock().backend_pid();
+
+        let other = shared.clone();
+        let handle = std::thread::spawn(move || other.lock().backend_pid());
+        assert_eq!(handle.join().unwrap(), pid);
+
+        let conn = shared.into_inner().unwrap();
+        assert_eq!(conn.backend_pid(), pid);
+    }
+
+    #[test]
+    fn cancel() {
+        let shared = super::SharedConnection::new(crate::test::new_conn());
+
+        let other = shared.clone();
+        let handle = std::thread::spawn(move || other.lock().exec("SELECT pg_sleep(10)"));
+
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        shared.cancel().unwrap();
+
+        let result = handle.join().unwrap();
+        assert_eq!(
+            result.error_field(crate::result::ErrorField::Sqlstate),
+            Ok(Some("57014"))
+        );
+    }
+}
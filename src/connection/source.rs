@@ -0,0 +1,32 @@
+use std::os::unix::io::AsRawFd;
+
+/**
+ * The connection socket, registered as is in the mio registry.
+ *
+ * libpq may change the socket while connecting or resetting, the connection must be
+ * reregistered after these steps.
+ */
+#[cfg_attr(docsrs, doc(cfg(feature = "mio")))]
+impl mio::event::Source for crate::Connection {
+    fn register(
+        &mut self,
+        registry: &mio::Registry,
+        token: mio::Token,
+        interests: mio::Interest,
+    ) -> std::io::Result<()> {
+        mio::unix::SourceFd(&self.as_raw_fd()).register(registry, token, interests)
+    }
+
+    fn reregister(
+        &mut self,
+        registry: &mio::Registry,
+        token: mio::Token,
+        interests: mio::Interest,
+    ) -> std::io::Result<()> {
+        mio::unix::SourceFd(&self.as_raw_fd()).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &mio::Registry) -> std::io::Result<()> {
+        mio::unix::SourceFd(&self.as_raw_fd()).deregister(registry)
+    }
+}
@@ -0,0 +1,138 @@
+static NEXT_ID: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/**
+ * A named prepared statement, created by `libpq::Connection::statement`.
+ *
+ * The statement is closed when the handle is dropped, to not accumulate statements on long-lived
+ * connections.
+ *
+ * Dropping the handle closes the statement right away, which fails while the connection is busy
+ * with a pipeline or a row stream and, without the `v17` feature, inside a failed transaction.
+ * The failure is only logged and the statement stays on the server until the session ends, use
+ * `Statement::close` to handle it.
+ *
+ * # Examples
+ *
+ * ```
+ * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+ * let conn = libpq::Connection::new(&dsn).unwrap();
+ * let statement = conn.statement("SELECT $1::int4 + 1", &[]).unwrap();
+ *
+ * let result = statement.execute(&[&41]).unwrap();
+ * assert_eq!(result.get::<i32>(0, 0), Ok(42));
+ *
+ * statement.close().unwrap();
+ * ```
+ */
+#[derive(Debug)]
+pub struct Statement<'conn> {
+    conn: &'conn crate::Connection,
+    name: String,
+    closed: bool,
+}
+
+impl<'conn> Statement<'conn> {
+    pub(crate) fn new(conn: &'conn crate::Connection, name: String) -> Self {
+        Self {
+            conn,
+            name,
+            closed: false,
+        }
+    }
+
+    pub(crate) fn next_name() -> String {
+        let id = NEXT_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+
+        format!("libpq_statement_{id}")
+    }
+
+    /**
+     * Returns the statement name, to use with the `*_prepared` methods of the connection.
+     */
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /**
+     * Executes the statement with parameters converted from rust values, see
+     * `libpq::Connection::exec_prepared_typed`.
+     */
+    pub fn execute(
+        &self,
+        params: &[&dyn crate::types::ToSql],
+    ) -> crate::errors::Result<crate::PQResult> {
+        let result =
+            self.conn
+                .exec_prepared_typed(Some(&self.name), params, crate::Format::Text)?;
+
+        self.conn.check(result)
+    }
+
+    /**
+     * Returns the statement description, see `libpq::Connection::describe_prepared`.
+     */
+    pub fn describe(&self) -> crate::errors::Result<crate::PQResult> {
+        self.conn
+            .check(self.conn.describe_prepared(Some(&self.name)))
+    }
+
+    /**
+     * Closes the statement, and reports a failure unlike dropping it.
+     */
+    pub fn close(mut self) -> crate::errors::Result {
+        self.closed = true;
+        self.conn.close_prepared(Some(&self.name))?;
+
+        Ok(())
+    }
+}
+
+impl Drop for Statement<'_> {
+    fn drop(&mut self) {
+        if self.closed {
+            return;
+        }
+
+        if let Err(err) = self.conn.close_prepared(Some(&self.name)) {
+            log::warn!("Failed to close statement {}: {err}", self.name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    fn prepared(conn: &crate::Connection, name: &str) -> bool {
+        conn.exec_typed(
+            "SELECT 1 FROM pg_prepared_statements WHERE name = $1",
+            &[&name],
+            crate::Format::Text,
+        )
+        .unwrap()
+        .ntuples()
+            == 1
+    }
+
+    #[test]
+    fn statement() {
+        let conn = crate::test::new_conn();
+
+        let statement = conn.statement("SELECT $1::text", &[]).unwrap();
+        let name = statement.name().to_string();
+        assert!(prepared(&conn, &name));
+
+        assert_eq!(statement.describe().unwrap().nparams(), 1);
+        let result = statement.execute(&[&"value"]).unwrap();
+        assert_eq!(result.get::<&str>(0, 0), Ok("value"));
+        assert!(statement.execute(&[]).is_err());
+
+        drop(statement);
+        assert!(!prepared(&conn, &name));
+
+        let statement = conn.statement("SELECT 1", &[]).unwrap();
+        let name = statement.name().to_string();
+        statement.close().unwrap();
+        assert!(!prepared(&conn, &name));
+
+        assert!(conn.statement("SELECT invalid", &[]).is_err());
+    }
+}
@@ -28,6 +28,10 @@ pub enum Status {
     #[cfg(feature = "v11")]
     #[cfg_attr(docsrs, doc(cfg(feature = "v11")))]
     CheckTarget,
+    /** Connection not yet started, e.g. a cancel connection before its start. */
+    #[cfg(feature = "v17")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "v17")))]
+    Allocated,
 }
 
 impl From<pq_sys::ConnStatusType> for Status {
@@ -50,6 +54,9 @@ impl From<pq_sys::ConnStatusType> for Status {
             #[cfg(feature = "v11")]
             #[cfg_attr(docsrs, doc(cfg(feature = "v11")))]
             pq_sys::ConnStatusType::CONNECTION_CHECK_TARGET => Self::CheckTarget,
+            #[cfg(feature = "v17")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "v17")))]
+            pq_sys::ConnStatusType::CONNECTION_ALLOCATED => Self::Allocated,
             #[allow(unreachable_patterns)]
             _ => unreachable!(),
         }
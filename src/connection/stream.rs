@@ -0,0 +1,97 @@
+/**
+ * Iterator over the rows of a query run in single-row mode, see
+ * `libpq::Connection::query_stream`.
+ *
+ * Each item is a result holding one row, or up to the chunk size with
+ * `libpq::Connection::query_chunks`. Dropping the iterator before the end cancels the query
+ * and discards the remaining rows.
+ *
+ * The stream is meant for queries returning rows, a command without rows ends it without any
+ * item.
+ */
+pub struct RowStream<'conn> {
+    conn: &'conn crate::Connection,
+    done: bool,
+}
+
+impl<'conn> RowStream<'conn> {
+    pub(crate) fn new(conn: &'conn crate::Connection) -> Self {
+        Self { conn, done: false }
+    }
+
+    pub(crate) fn drain(&mut self) {
+        self.done = true;
+
+        while self.conn.result().is_some() {}
+    }
+}
+
+impl Iterator for RowStream<'_> {
+    type Item = crate::errors::Result<crate::PQResult>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let Some(result) = self.conn.result() else {
+            self.done = true;
+            return None;
+        };
+
+        match result.status() {
+            crate::Status::SingleTuple => Some(Ok(result)),
+            #[cfg(feature = "v17")]
+            crate::Status::TuplesChunk => Some(Ok(result)),
+            // The final result has no row.
+            crate::Status::TuplesOk | crate::Status::CommandOk | crate::Status::EmptyQuery => {
+                self.drain();
+                None
+            }
+            _ => {
+                let result = self.conn.check(result);
+                self.drain();
+
+                match result {
+                    Ok(result) => Some(Err(crate::errors::Error::Backend(format!(
+                        "Unexpected result status {:?}",
+                        result.status()
+                    )))),
+                    Err(err) => Some(Err(err)),
+                }
+            }
+        }
+    }
+}
+
+impl std::iter::FusedIterator for RowStream<'_> {}
+
+impl Drop for RowStream<'_> {
+    fn drop(&mut self) {
+        if self.done {
+            return;
+        }
+
+        // Canceling opens a connection, it's skipped if the end of the query is already
+        // received, typically when only the final result is left.
+        if self.conn.consume_input().is_ok() {
+            while !self.conn.is_busy() {
+                match self.conn.result().map(|x| x.status()) {
+                    Some(crate::Status::SingleTuple) => (),
+                    #[cfg(feature = "v17")]
+                    Some(crate::Status::TuplesChunk) => (),
+                    _ => {
+                        self.drain();
+                        return;
+                    }
+                }
+            }
+        }
+
+        if let Err(err) = self.conn.cancel().request() {
+            log::warn!("Failed to cancel streamed query: {err}");
+        }
+
+        self.drain();
+    }
+}
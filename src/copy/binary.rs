@@ -0,0 +1,560 @@
+const SIGNATURE: &[u8] = b"PGCOPY\n\xFF\r\n\0";
+const HEADER_LEN: usize = SIGNATURE.len() + 4 + 4;
+const FLAG_OIDS: u32 = 1 << 16;
+const FLAGS_CRITICAL: u32 = 0xFFFF;
+
+/**
+ * Encodes rows in the binary `COPY` format, for `libpq::Connection::put_copy_data` or a
+ * `libpq::copy::CopyInWriter`.
+ *
+ * See [Binary Format](https://www.postgresql.org/docs/current/sql-copy.html#id-1.9.3.55.9.4).
+ *
+ * # Examples
+ *
+ * ```
+ * use std::io::Write;
+ *
+ * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+ * let conn = libpq::Connection::new(&dsn).unwrap();
+ * conn.try_exec("CREATE TEMPORARY TABLE t (id int, name text)").unwrap();
+ *
+ * let mut encoder = libpq::copy::binary::Encoder::new(&[libpq::types::INT4, libpq::types::TEXT]);
+ * encoder.write_row(&[&1, &"foo"]).unwrap();
+ * encoder.write_row(&[&2, &None::<String>]).unwrap();
+ *
+ * let mut writer = conn.copy_in("COPY t FROM STDIN (FORMAT binary)").unwrap();
+ * writer.write_all(&encoder.finish()).unwrap();
+ * writer.finish().unwrap();
+ * ```
+ */
+#[derive(Clone, Debug)]
+pub struct Encoder {
+    types: Vec<crate::Type>,
+    buffer: Vec<u8>,
+}
+
+impl Encoder {
+    /**
+     * Creates an encoder for rows of `types`, the header is written in the buffer.
+     */
+    pub fn new(types: &[crate::Type]) -> Self {
+        let mut buffer = Vec::with_capacity(HEADER_LEN);
+        buffer.extend_from_slice(SIGNATURE);
+        buffer.extend_from_slice(&0u32.to_be_bytes());
+        buffer.extend_from_slice(&0u32.to_be_bytes());
+
+        Self {
+            types: types.to_vec(),
+            buffer,
+        }
+    }
+
+    /**
+     * Appends a row, each value must be of the type of its column.
+     */
+    pub fn write_row(&mut self, row: &[&dyn crate::types::ToSql]) -> crate::errors::Result {
+        if row.len() != self.types.len() {
+            return Err(crate::errors::Error::Conversion(format!(
+                "expected {} values, got {}",
+                self.types.len(),
+                row.len()
+            )));
+        }
+
+        let count = i16::try_from(row.len())
+            .map_err(|_| crate::errors::Error::Conversion("too many values".to_string()))?;
+
+        let start = self.buffer.len();
+        self.buffer.extend_from_slice(&count.to_be_bytes());
+
+        for (value, ty) in row.iter().zip(&self.types) {
+            if let Err(err) = Self::write_value(&mut self.buffer, *value, ty) {
+                self.buffer.truncate(start);
+                return Err(err);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_value(
+        buffer: &mut Vec<u8>,
+        value: &dyn crate::types::ToSql,
+        ty: &crate::Type,
+    ) -> crate::errors::Result {
+        if value.ty().oid != ty.oid {
+            return Err(crate::errors::Error::Conversion(format!(
+                "expected a {} value, got {}",
+                ty.name,
+                value.ty().name
+            )));
+        }
+
+        match value.to_sql(crate::Format::Binary)? {
+            Some(data) => {
+                let len = i32::try_from(data.len())
+                    .map_err(|_| crate::errors::Error::Conversion("value too large".to_string()))?;
+
+                buffer.extend_from_slice(&len.to_be_bytes());
+                buffer.extend_from_slice(&data);
+            }
+            None => buffer.extend_from_slice(&(-1i32).to_be_bytes()),
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Returns the encoded data and empties the buffer, to send rows by batches.
+     */
+    pub fn take(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.buffer)
+    }
+
+    /**
+     * Returns the remaining data followed by the trailer.
+     */
+    pub fn finish(mut self) -> Vec<u8> {
+        self.buffer.extend_from_slice(&(-1i16).to_be_bytes());
+        self.buffer
+    }
+
+    /**
+     * Returns the size of the buffered data.
+     */
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /**
+     * Returns `true` if there is no buffered data.
+     */
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+}
+
+/**
+ * A row decoded by a `Decoder`.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Row {
+    types: std::sync::Arc<[crate::Type]>,
+    fields: Vec<Option<Vec<u8>>>,
+}
+
+impl Row {
+    /**
+     * Returns the number of fields.
+     */
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /**
+     * Returns `true` if the row has no field.
+     */
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /**
+     * Returns the raw binary value of a field, `None` if it is `NULL`.
+     */
+    pub fn raw(&self, index: usize) -> Option<&[u8]> {
+        self.fields.get(index).and_then(Option::as_deref)
+    }
+
+    /**
+     * Converts a field to a rust type.
+     */
+    pub fn get<'a, T: crate::types::FromSql<'a>>(
+        &'a self,
+        index: usize,
+    ) -> crate::errors::Result<T> {
+        let Some(field) = self.fields.get(index) else {
+            return Err(crate::errors::Error::ColumnNotFound(index.to_string()));
+        };
+
+        T::from_sql(&self.types[index], crate::Format::Binary, field.as_deref())
+    }
+}
+
+/**
+ * Decodes a binary `COPY` stream, fed with the data of `libpq::Connection::copy_data` or read
+ * from a `libpq::copy::CopyOutReader`.
+ *
+ * # Examples
+ *
+ * ```
+ * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+ * let conn = libpq::Connection::new(&dsn).unwrap();
+ * let result = conn.exec("COPY (SELECT 1, 'foo') TO STDOUT (FORMAT binary)");
+ * assert_eq!(result.status(), libpq::Status::CopyOut);
+ *
+ * let mut decoder = libpq::copy::binary::Decoder::new(&[libpq::types::INT4, libpq::types::TEXT]);
+ * while let Ok(data) = conn.copy_data(false) {
+ *     decoder.feed(&data);
+ * }
+ *
+ * let row = decoder.next_row().unwrap().unwrap();
+ * assert_eq!(row.get::<i32>(0), Ok(1));
+ * assert_eq!(row.get::<&str>(1), Ok("foo"));
+ * assert_eq!(decoder.next_row(), Ok(None));
+ * assert!(decoder.is_done());
+ * ```
+ */
+#[derive(Clone, Debug)]
+pub struct Decoder {
+    types: std::sync::Arc<[crate::Type]>,
+    buffer: Vec<u8>,
+    // Start of the data not decoded yet.
+    pos: usize,
+    header: bool,
+    done: bool,
+}
+
+impl Decoder {
+    /**
+     * Creates a decoder for rows of `types`.
+     */
+    pub fn new(types: &[crate::Type]) -> Self {
+        Self {
+            types: types.into(),
+            buffer: Vec::new(),
+            pos: 0,
+            header: false,
+            done: false,
+        }
+    }
+
+    /**
+     * Appends data to decode.
+     */
+    pub fn feed(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    /**
+     * Returns `true` once the trailer was decoded.
+     */
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /**
+     * Decodes the next row, returns `None` if more data is needed or after the trailer.
+     */
+    pub fn next_row(&mut self) -> crate::errors::Result<Option<Row>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        if !self.header {
+            let Some(len) = self.header()? else {
+                return Ok(None);
+            };
+
+            self.consume(len);
+            self.header = true;
+        }
+
+        let mut reader = Reader::new(&self.buffer[self.pos..]);
+
+        let Some(count) = reader.i16() else {
+            return Ok(None);
+        };
+
+        if count == -1 {
+            self.done = true;
+            self.consume(reader.pos);
+
+            if self.pos < self.buffer.len() {
+                return Err(Self::error("data after the trailer"));
+            }
+
+            return Ok(None);
+        }
+
+        if count as usize != self.types.len() {
+            return Err(Self::error(&format!(
+                "expected {} fields, got {count}",
+                self.types.len()
+            )));
+        }
+
+        let mut fields = Vec::with_capacity(self.types.len());
+
+        for _ in 0..count {
+            let Some(len) = reader.i32() else {
+                return Ok(None);
+            };
+
+            let field = match len {
+                -1 => None,
+                len if len < 0 => return Err(Self::error(&format!("invalid field length {len}"))),
+                len => match reader.bytes(len as usize) {
+                    Some(data) => Some(data.to_vec()),
+                    None => return Ok(None),
+                },
+            };
+
+            fields.push(field);
+        }
+
+        self.consume(reader.pos);
+
+        Ok(Some(Row {
+            types: self.types.clone(),
+            fields,
+        }))
+    }
+
+    /**
+     * Validates the header, returns its length or `None` if it isn't complete.
+     */
+    fn header(&self) -> crate::errors::Result<Option<usize>> {
+        let mut reader = Reader::new(&self.buffer[self.pos..]);
+
+        let Some(signature) = reader.bytes(SIGNATURE.len()) else {
+            return Ok(None);
+        };
+
+        if signature != SIGNATURE {
+            return Err(Self::error("invalid signature"));
+        }
+
+        let (Some(flags), Some(extension)) = (reader.i32(), reader.i32()) else {
+            return Ok(None);
+        };
+
+        let flags = flags as u32;
+
+        if flags & FLAG_OIDS != 0 {
+            return Err(Self::error("OIDs are not supported"));
+        }
+
+        if flags & FLAGS_CRITICAL != 0 {
+            return Err(Self::error(&format!("unknown critical flags {flags:#x}")));
+        }
+
+        if extension < 0 {
+            return Err(Self::error(&format!(
+                "invalid extension length {extension}"
+            )));
+        }
+
+        // The extension area content is skipped, no extension is defined yet.
+        if reader.bytes(extension as usize).is_none() {
+            return Ok(None);
+        }
+
+        Ok(Some(reader.pos))
+    }
+
+    /**
+     * Skips decoded data, the buffer is only compacted once most of it is decoded to keep
+     * decoding linear.
+     */
+    fn consume(&mut self, len: usize) {
+        self.pos += len;
+
+        if self.pos * 2 > self.buffer.len() {
+            self.buffer.drain(..self.pos);
+            self.pos = 0;
+        }
+    }
+
+    fn error(message: &str) -> crate::errors::Error {
+        crate::errors::Error::Conversion(format!("invalid binary COPY data: {message}"))
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.pos..self.pos.checked_add(len)?)?;
+        self.pos += len;
+
+        Some(bytes)
+    }
+
+    fn i16(&mut self) -> Option<i16> {
+        self.bytes(2)
+            .map(|x| i16::from_be_bytes(x.try_into().unwrap()))
+    }
+
+    fn i32(&mut self) -> Option<i32> {
+        self.bytes(4)
+            .map(|x| i32::from_be_bytes(x.try_into().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{Read, Write};
+
+    const TYPES: &[crate::Type] = &[crate::types::INT4, crate::types::TEXT, crate::types::BYTEA];
+
+    #[test]
+    fn encode() {
+        let mut encoder = super::Encoder::new(&[crate::types::INT2]);
+        encoder.write_row(&[&1i16]).unwrap();
+        encoder.write_row(&[&None::<i16>]).unwrap();
+
+        assert_eq!(
+            encoder.finish(),
+            b"PGCOPY\n\xFF\r\n\0\0\0\0\0\0\0\0\0\
+              \0\x01\0\0\0\x02\0\x01\
+              \0\x01\xFF\xFF\xFF\xFF\
+              \xFF\xFF"
+        );
+    }
+
+    #[test]
+    fn encode_invalid() {
+        let mut encoder = super::Encoder::new(TYPES);
+        let len = encoder.len();
+
+        assert!(encoder.write_row(&[&1]).is_err());
+        assert!(encoder
+            .write_row(&[&1i64, &"foo", &b"bar".to_vec()])
+            .is_err());
+        assert_eq!(encoder.len(), len);
+
+        let types = vec![crate::types::INT4; i16::MAX as usize + 1];
+        let values = vec![&1 as &dyn crate::types::ToSql; types.len()];
+        let mut encoder = super::Encoder::new(&types);
+        let len = encoder.len();
+        assert!(encoder.write_row(&values).is_err());
+        assert_eq!(encoder.len(), len);
+    }
+
+    #[test]
+    fn decode() {
+        let mut decoder = super::Decoder::new(&[crate::types::INT2]);
+        let data = b"PGCOPY\n\xFF\r\n\0\0\0\0\0\0\0\0\x02ex\0\x01\0\0\0\x02\0\x01\xFF\xFF";
+
+        // Fed byte by byte, rows are only returned once complete.
+        let mut rows = Vec::new();
+        for byte in data {
+            decoder.feed(&[*byte]);
+
+            if let Some(row) = decoder.next_row().unwrap() {
+                rows.push(row);
+            }
+        }
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].get::<i16>(0), Ok(1));
+        assert!(decoder.is_done());
+    }
+
+    #[test]
+    fn decode_chunks() {
+        let mut encoder = super::Encoder::new(TYPES);
+        for id in 0..100_000 {
+            encoder
+                .write_row(&[&id, &format!("row {id}"), &vec![id as u8; 3]])
+                .unwrap();
+        }
+        let data = encoder.finish();
+
+        let mut decoder = super::Decoder::new(TYPES);
+        let mut count = 0;
+        for chunk in data.chunks(7) {
+            decoder.feed(chunk);
+
+            while let Some(row) = decoder.next_row().unwrap() {
+                assert_eq!(row.get::<i32>(0), Ok(count));
+                count += 1;
+            }
+        }
+
+        assert_eq!(count, 100_000);
+        assert!(decoder.is_done());
+    }
+
+    #[test]
+    fn decode_invalid() {
+        let header = b"PGCOPY\n\xFF\r\n\0";
+
+        let mut decoder = super::Decoder::new(&[]);
+        decoder.feed(b"PGCOPX\n\xFF\r\n\0\0\0\0\0\0\0\0\0");
+        assert!(decoder.next_row().is_err());
+
+        let mut decoder = super::Decoder::new(&[]);
+        decoder.feed(header);
+        decoder.feed(b"\0\x01\0\0\0\0\0\0");
+        assert!(decoder.next_row().is_err());
+
+        let mut decoder = super::Decoder::new(&[]);
+        decoder.feed(header);
+        decoder.feed(b"\0\0\0\x01\0\0\0\0");
+        assert!(decoder.next_row().is_err());
+
+        // Non-critical flags are ignored.
+        let mut decoder = super::Decoder::new(&[]);
+        decoder.feed(header);
+        decoder.feed(b"\x01\0\0\0\0\0\0\0\xFF\xFF");
+        assert_eq!(decoder.next_row(), Ok(None));
+        assert!(decoder.is_done());
+
+        let mut decoder = super::Decoder::new(&[crate::types::INT2]);
+        decoder.feed(header);
+        decoder.feed(b"\0\0\0\0\0\0\0\0\0\x02");
+        assert!(decoder.next_row().is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        let conn = crate::test::new_conn();
+        conn.try_exec("CREATE TEMPORARY TABLE tmp (id int, name text, data bytea)")
+            .unwrap();
+
+        let mut encoder = super::Encoder::new(TYPES);
+        for id in 0..100 {
+            encoder
+                .write_row(&[&id, &format!("row {id}"), &vec![id as u8; 3]])
+                .unwrap();
+        }
+        encoder
+            .write_row(&[&100, &None::<String>, &None::<Vec<u8>>])
+            .unwrap();
+
+        let mut writer = conn.copy_in("COPY tmp FROM STDIN (FORMAT binary)").unwrap();
+        writer.write_all(&encoder.take()).unwrap();
+        writer.write_all(&encoder.finish()).unwrap();
+        assert_eq!(writer.finish().unwrap().cmd_tuples(), Ok(101));
+
+        let mut reader = conn
+            .copy_out("COPY (SELECT * FROM tmp ORDER BY id) TO STDOUT (FORMAT binary)")
+            .unwrap();
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data).unwrap();
+
+        let mut decoder = super::Decoder::new(TYPES);
+        decoder.feed(&data);
+
+        let mut rows = Vec::new();
+        while let Some(row) = decoder.next_row().unwrap() {
+            rows.push(row);
+        }
+        assert!(decoder.is_done());
+        assert_eq!(rows.len(), 101);
+
+        assert_eq!(rows[42].get::<i32>(0), Ok(42));
+        assert_eq!(rows[42].get::<String>(1), Ok("row 42".to_string()));
+        assert_eq!(rows[42].get::<Vec<u8>>(2), Ok(vec![42; 3]));
+        assert_eq!(rows[100].get::<Option<&str>>(1), Ok(None));
+        assert_eq!(rows[100].raw(2), None);
+    }
+}
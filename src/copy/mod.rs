@@ -0,0 +1,366 @@
+pub mod binary;
+pub mod text;
+
+/**
+ * Writer returned by `libpq::Connection::copy_in`.
+ *
+ * The data is sent as is, in the format of the `COPY` command. The copy must be completed with
+ * `CopyInWriter::finish`, it is aborted if the writer is dropped before.
+ *
+ * # Examples
+ *
+ * ```
+ * use std::io::Write;
+ *
+ * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+ * let conn = libpq::Connection::new(&dsn).unwrap();
+ * conn.try_exec("CREATE TEMPORARY TABLE t (id int, name text)").unwrap();
+ *
+ * let mut writer = conn.copy_in("COPY t FROM STDIN").unwrap();
+ * writer.write_all(b"1\tfoo\n2\tbar\n").unwrap();
+ * let result = writer.finish().unwrap();
+ * assert_eq!(result.cmd_tuples(), Ok(2));
+ * ```
+ */
+#[derive(Debug)]
+pub struct CopyInWriter<'conn> {
+    conn: &'conn crate::Connection,
+    done: bool,
+}
+
+impl<'conn> CopyInWriter<'conn> {
+    pub(crate) fn new(
+        conn: &'conn crate::Connection,
+        command: &str,
+    ) -> crate::errors::Result<Self> {
+        start(conn, command, crate::Status::CopyIn)?;
+
+        Ok(Self { conn, done: false })
+    }
+
+    /**
+     * Ends the copy and returns the result of the command.
+     */
+    pub fn finish(mut self) -> crate::errors::Result<crate::PQResult> {
+        self.done = true;
+        self.conn.put_copy_end(None)?;
+
+        last_result(self.conn)
+    }
+
+    /**
+     * Aborts the copy, the command fails with `message`.
+     */
+    pub fn abort(mut self, message: &str) -> crate::errors::Result {
+        self.done = true;
+        self.conn.put_copy_end(Some(message))?;
+
+        // The failure is expected.
+        last_result(self.conn).ok();
+
+        Ok(())
+    }
+}
+
+impl std::io::Write for CopyInWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.conn
+            .put_copy_data(buf)
+            .map_err(std::io::Error::other)?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.conn.flush().map_err(std::io::Error::other)
+    }
+}
+
+impl Drop for CopyInWriter<'_> {
+    fn drop(&mut self) {
+        if !self.done {
+            log::warn!("COPY IN writer dropped before finish, aborting");
+
+            self.conn
+                .put_copy_end(Some("COPY aborted by the client"))
+                .ok();
+            last_result(self.conn).ok();
+        }
+    }
+}
+
+/**
+ * Reader returned by `libpq::Connection::copy_out`.
+ *
+ * The remaining data is read and discarded if the reader is dropped before the end of the copy.
+ *
+ * # Examples
+ *
+ * ```
+ * use std::io::BufRead;
+ *
+ * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+ * let conn = libpq::Connection::new(&dsn).unwrap();
+ *
+ * let reader = conn
+ *     .copy_out("COPY (SELECT generate_series(1, 3)) TO STDOUT")
+ *     .unwrap();
+ * let lines = reader.lines().collect::<Result<Vec<_>, _>>().unwrap();
+ * assert_eq!(lines, ["1", "2", "3"]);
+ * ```
+ */
+#[derive(Debug)]
+pub struct CopyOutReader<'conn> {
+    conn: &'conn crate::Connection,
+    buffer: Option<crate::connection::PqBytes>,
+    pos: usize,
+    result: Option<crate::PQResult>,
+    done: bool,
+}
+
+impl<'conn> CopyOutReader<'conn> {
+    pub(crate) fn new(
+        conn: &'conn crate::Connection,
+        command: &str,
+    ) -> crate::errors::Result<Self> {
+        start(conn, command, crate::Status::CopyOut)?;
+
+        Ok(Self {
+            conn,
+            buffer: None,
+            pos: 0,
+            result: None,
+            done: false,
+        })
+    }
+
+    /**
+     * Discards the remaining data and returns the result of the command.
+     */
+    pub fn finish(mut self) -> crate::errors::Result<crate::PQResult> {
+        while self.next()? {}
+
+        match self.result.take() {
+            Some(result) => Ok(result),
+            None => self.conn.error(),
+        }
+    }
+
+    /**
+     * Receives the next row, returns `false` at the end of the copy.
+     */
+    fn next(&mut self) -> crate::errors::Result<bool> {
+        self.buffer = None;
+        self.pos = 0;
+
+        if self.done {
+            return Ok(false);
+        }
+
+        // The copy is over once it ended or failed, it must not be read again.
+        match self.conn.next_copy_data() {
+            Ok(Some(data)) => {
+                self.buffer = Some(data);
+                Ok(true)
+            }
+            Ok(None) => {
+                self.done = true;
+                self.result = Some(last_result(self.conn)?);
+                Ok(false)
+            }
+            Err(err) => {
+                self.done = true;
+                Err(err)
+            }
+        }
+    }
+}
+
+impl std::io::Read for CopyOutReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        use std::io::BufRead;
+
+        let available = self.fill_buf()?;
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.consume(len);
+
+        Ok(len)
+    }
+}
+
+impl std::io::BufRead for CopyOutReader<'_> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        let len = self.buffer.as_deref().map_or(0, <[u8]>::len);
+
+        if self.pos >= len {
+            self.next().map_err(std::io::Error::other)?;
+        }
+
+        Ok(self
+            .buffer
+            .as_deref()
+            .map_or(&[][..], |buffer| &buffer[self.pos..]))
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos += amt;
+    }
+}
+
+impl Drop for CopyOutReader<'_> {
+    fn drop(&mut self) {
+        if !self.done {
+            while let Ok(true) = self.next() {}
+        }
+    }
+}
+
+fn start(
+    conn: &crate::Connection,
+    command: &str,
+    expected: crate::Status,
+) -> crate::errors::Result {
+    let result = conn.check(conn.exec(command))?;
+    let status = result.status();
+
+    if status == expected {
+        return Ok(());
+    }
+
+    // Leaves the unexpected copy state.
+    match status {
+        crate::Status::CopyIn => {
+            conn.put_copy_end(Some("Unexpected COPY FROM STDIN")).ok();
+            last_result(conn).ok();
+        }
+        crate::Status::CopyOut => {
+            while let Ok(Some(_)) = conn.next_copy_data() {}
+            last_result(conn).ok();
+        }
+        _ => (),
+    }
+
+    Err(crate::errors::Error::Backend(format!(
+        "Expected {expected:?} status, got {status:?}"
+    )))
+}
+
+/**
+ * Reads the results of the finished copy, until the last one.
+ */
+fn last_result(conn: &crate::Connection) -> crate::errors::Result<crate::PQResult> {
+    let mut last = None;
+
+    // All the results must be read before the next command, the first error is kept.
+    while let Some(result) = conn.result() {
+        if !matches!(last, Some(Err(_))) {
+            last = Some(conn.check(result));
+        }
+    }
+
+    match last {
+        Some(result) => result,
+        None => conn.error(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{BufRead, Read, Write};
+
+    #[test]
+    fn copy_in() {
+        let conn = crate::test::new_conn();
+        conn.try_exec("CREATE TEMPORARY TABLE tmp (id int)")
+            .unwrap();
+
+        let mut writer = conn.copy_in("COPY tmp FROM STDIN").unwrap();
+        std::io::copy(&mut &b"1\n2\n3\n"[..], &mut writer).unwrap();
+        writer.flush().unwrap();
+        assert_eq!(writer.finish().unwrap().cmd_tuples(), Ok(3));
+
+        let result = conn.try_exec("SELECT count(*) FROM tmp").unwrap();
+        assert_eq!(result.value(0, 0), Some(&b"3"[..]));
+    }
+
+    #[test]
+    fn copy_in_error() {
+        let conn = crate::test::new_conn();
+        conn.try_exec("CREATE TEMPORARY TABLE tmp (id int)")
+            .unwrap();
+
+        let mut writer = conn.copy_in("COPY tmp FROM STDIN").unwrap();
+        writer.write_all(b"foo\n").unwrap();
+        let err = writer.finish().unwrap_err();
+        assert_eq!(
+            err.state(),
+            Some(&crate::state::INVALID_TEXT_REPRESENTATION)
+        );
+
+        assert!(conn.copy_in("COPY unknown FROM STDIN").is_err());
+        assert!(conn.copy_in("SELECT 1").is_err());
+        assert!(conn.copy_in("COPY tmp TO STDOUT").is_err());
+        assert!(conn.copy_out("COPY tmp FROM STDIN").is_err());
+        assert_eq!(conn.transaction_status(), crate::transaction::Status::Idle);
+    }
+
+    #[test]
+    fn copy_in_drop() {
+        let conn = crate::test::new_conn();
+        conn.try_exec("CREATE TEMPORARY TABLE tmp (id int)")
+            .unwrap();
+
+        let mut writer = conn.copy_in("COPY tmp FROM STDIN").unwrap();
+        writer.write_all(b"1\n").unwrap();
+        drop(writer);
+
+        let result = conn.try_exec("SELECT count(*) FROM tmp").unwrap();
+        assert_eq!(result.value(0, 0), Some(&b"0"[..]));
+    }
+
+    #[test]
+    fn copy_out() {
+        let conn = crate::test::new_conn();
+
+        let mut reader = conn
+            .copy_out("COPY (SELECT generate_series(1, 1000)) TO STDOUT")
+            .unwrap();
+        let mut data = String::new();
+        reader.read_to_string(&mut data).unwrap();
+        assert_eq!(data.lines().count(), 1000);
+        assert_eq!(reader.finish().unwrap().cmd_tuples(), Ok(1000));
+
+        let mut reader = conn
+            .copy_out("COPY (SELECT generate_series(1, 1000)) TO STDOUT")
+            .unwrap();
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        assert_eq!(line, "1\n");
+        drop(reader);
+
+        let result = conn.try_exec("SELECT 1").unwrap();
+        assert_eq!(result.value(0, 0), Some(&b"1"[..]));
+    }
+
+    #[test]
+    fn copy_out_error() {
+        let conn = crate::test::new_conn();
+
+        let mut reader = conn
+            .copy_out("COPY (SELECT 1 / (10 - x) FROM generate_series(1, 20) x) TO STDOUT")
+            .unwrap();
+        let mut data = Vec::new();
+        assert!(reader.read_to_end(&mut data).is_err());
+        drop(reader);
+
+        let reader = conn
+            .copy_out("COPY (SELECT 1 / (10 - x) FROM generate_series(1, 20) x) TO STDOUT")
+            .unwrap();
+        assert_eq!(
+            reader.finish().unwrap_err().state(),
+            Some(&crate::state::DIVISION_BY_ZERO)
+        );
+        assert!(conn.try_exec("SELECT 1").is_ok());
+    }
+}
@@ -0,0 +1,1019 @@
+/**
+ * Options of the text and CSV `COPY` formats.
+ *
+ * The `Display` implementation returns the options to use in the `COPY` command, except the
+ * `FORCE_*` ones which take column names and only change the codec behavior.
+ *
+ * See [COPY](https://www.postgresql.org/docs/current/sql-copy.html).
+ *
+ * # Examples
+ *
+ * ```
+ * let options = libpq::copy::text::Options::csv().delimiter(b';').header(true);
+ * assert_eq!(options.to_string(), "FORMAT csv, DELIMITER ';', HEADER");
+ * ```
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Options {
+    pub(crate) csv: bool,
+    pub(crate) delimiter: u8,
+    pub(crate) null: String,
+    pub(crate) header: bool,
+    pub(crate) quote: u8,
+    pub(crate) escape: u8,
+    pub(crate) force_quote_all: bool,
+    pub(crate) force_quote: Vec<usize>,
+    pub(crate) force_not_null: Vec<usize>,
+    pub(crate) force_null: Vec<usize>,
+}
+
+impl Options {
+    /**
+     * Default options of the text format.
+     */
+    pub fn text() -> Self {
+        Self {
+            csv: false,
+            delimiter: b'\t',
+            null: "\\N".to_string(),
+            header: false,
+            quote: b'"',
+            escape: b'"',
+            force_quote_all: false,
+            force_quote: Vec::new(),
+            force_not_null: Vec::new(),
+            force_null: Vec::new(),
+        }
+    }
+
+    /**
+     * Default options of the CSV format.
+     */
+    pub fn csv() -> Self {
+        Self {
+            csv: true,
+            delimiter: b',',
+            null: String::new(),
+            ..Self::text()
+        }
+    }
+
+    /**
+     * Sets the character that separates columns, `DELIMITER`.
+     */
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /**
+     * Sets the string that represents a null value, `NULL`.
+     */
+    pub fn null(mut self, null: &str) -> Self {
+        self.null = null.to_string();
+        self
+    }
+
+    /**
+     * Sets if the first line contains the column names, `HEADER`.
+     */
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /**
+     * Sets the CSV quoting character, `QUOTE`. The escape character is also changed if it was
+     * the quote.
+     */
+    pub fn quote(mut self, quote: u8) -> Self {
+        if self.escape == self.quote {
+            self.escape = quote;
+        }
+
+        self.quote = quote;
+        self
+    }
+
+    /**
+     * Sets the CSV character that escapes the quote in a quoted value, `ESCAPE`.
+     */
+    pub fn escape(mut self, escape: u8) -> Self {
+        self.escape = escape;
+        self
+    }
+
+    /**
+     * Quotes all the non-null CSV values when encoding, `FORCE_QUOTE *`.
+     */
+    pub fn force_quote_all(mut self) -> Self {
+        self.force_quote_all = true;
+        self
+    }
+
+    /**
+     * Quotes the non-null CSV values of these columns when encoding, `FORCE_QUOTE`.
+     */
+    pub fn force_quote(mut self, columns: &[usize]) -> Self {
+        self.force_quote = columns.to_vec();
+        self
+    }
+
+    /**
+     * Never decodes the CSV values of these columns as null, `FORCE_NOT_NULL`.
+     */
+    pub fn force_not_null(mut self, columns: &[usize]) -> Self {
+        self.force_not_null = columns.to_vec();
+        self
+    }
+
+    /**
+     * Decodes the CSV values of these columns matching the null string as null, even if quoted,
+     * `FORCE_NULL`.
+     */
+    pub fn force_null(mut self, columns: &[usize]) -> Self {
+        self.force_null = columns.to_vec();
+        self
+    }
+
+    fn literal(value: &str) -> String {
+        format!("'{}'", value.replace('\'', "''"))
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self::text()
+    }
+}
+
+impl std::fmt::Display for Options {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let defaults = if self.csv { Self::csv() } else { Self::text() };
+
+        write!(f, "FORMAT {}", if self.csv { "csv" } else { "text" })?;
+
+        if self.delimiter != defaults.delimiter {
+            let delimiter = (self.delimiter as char).to_string();
+            write!(f, ", DELIMITER {}", Self::literal(&delimiter))?;
+        }
+
+        if self.null != defaults.null {
+            write!(f, ", NULL {}", Self::literal(&self.null))?;
+        }
+
+        if self.header {
+            write!(f, ", HEADER")?;
+        }
+
+        if self.csv && self.quote != defaults.quote {
+            let quote = (self.quote as char).to_string();
+            write!(f, ", QUOTE {}", Self::literal(&quote))?;
+        }
+
+        if self.csv && self.escape != self.quote {
+            let escape = (self.escape as char).to_string();
+            write!(f, ", ESCAPE {}", Self::literal(&escape))?;
+        }
+
+        Ok(())
+    }
+}
+
+/**
+ * Encodes rows in the text or CSV `COPY` format, for `libpq::Connection::put_copy_data` or a
+ * `libpq::copy::CopyInWriter`.
+ *
+ * # Examples
+ *
+ * ```
+ * use std::io::Write;
+ *
+ * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+ * let conn = libpq::Connection::new(&dsn).unwrap();
+ * conn.try_exec("CREATE TEMPORARY TABLE t (id int, name text)").unwrap();
+ *
+ * let options = libpq::copy::text::Options::csv();
+ * let mut encoder = libpq::copy::text::Encoder::new(options.clone());
+ * encoder.write_row(&[Some("1"), Some("multi\nline, \"quoted\"")]).unwrap();
+ * encoder.write_values(&[&2, &None::<String>]).unwrap();
+ *
+ * let mut writer = conn.copy_in(&format!("COPY t FROM STDIN ({options})")).unwrap();
+ * writer.write_all(&encoder.finish()).unwrap();
+ * writer.finish().unwrap();
+ * ```
+ */
+#[derive(Clone, Debug)]
+pub struct Encoder {
+    options: Options,
+    buffer: Vec<u8>,
+}
+
+impl Encoder {
+    pub fn new(options: Options) -> Self {
+        Self {
+            options,
+            buffer: Vec::new(),
+        }
+    }
+
+    /**
+     * Appends the header line, if the `HEADER` option is used.
+     */
+    pub fn write_header(&mut self, names: &[&str]) -> crate::errors::Result {
+        let row = names.iter().map(|x| Some(*x)).collect::<Vec<_>>();
+
+        self.write(&row, true)
+    }
+
+    /**
+     * Appends a row, `None` is written as the null string.
+     *
+     * Fails, without writing anything, if a value of the text format can't be distinguished from
+     * the null string, for example an empty value with `NULL ''`.
+     */
+    pub fn write_row(&mut self, row: &[Option<&str>]) -> crate::errors::Result {
+        self.write(row, false)
+    }
+
+    /**
+     * Appends a row of values converted to their text representation.
+     */
+    pub fn write_values(&mut self, row: &[&dyn crate::types::ToSql]) -> crate::errors::Result {
+        let values = row
+            .iter()
+            .map(|value| match value.to_sql(crate::Format::Text)? {
+                Some(value) => Ok(Some(std::str::from_utf8(&value)?.to_string())),
+                None => Ok(None),
+            })
+            .collect::<crate::errors::Result<Vec<_>>>()?;
+
+        let row = values.iter().map(Option::as_deref).collect::<Vec<_>>();
+        self.write_row(&row)
+    }
+
+    /**
+     * `FORCE_QUOTE` doesn't apply to the header.
+     */
+    fn write(&mut self, row: &[Option<&str>], header: bool) -> crate::errors::Result {
+        let start = self.buffer.len();
+
+        for (column, value) in row.iter().enumerate() {
+            if column > 0 {
+                self.buffer.push(self.options.delimiter);
+            }
+
+            match value {
+                None => self.buffer.extend_from_slice(self.options.null.as_bytes()),
+                Some(value) if self.options.csv => self.write_csv(column, value, header),
+                Some(value) => {
+                    if let Err(err) = self.write_text(value) {
+                        self.buffer.truncate(start);
+                        return Err(err);
+                    }
+                }
+            }
+        }
+
+        self.buffer.push(b'\n');
+
+        Ok(())
+    }
+
+    fn write_text(&mut self, value: &str) -> crate::errors::Result {
+        let start = self.buffer.len();
+        self.escape_text(value, None);
+
+        if self.buffer[start..] != *self.options.null.as_bytes() {
+            return Ok(());
+        }
+
+        // A value matching the null string needs one more escaped character, `\` followed by a
+        // character is this character unless it starts an escape sequence.
+        let escaped = value.bytes().position(|x| {
+            x.is_ascii() && !b"\\\n\r\tbfnrtvx01234567".contains(&x) && x != self.options.delimiter
+        });
+
+        self.buffer.truncate(start);
+
+        match escaped {
+            Some(position) => {
+                self.escape_text(value, Some(position));
+                Ok(())
+            }
+            None => Err(crate::errors::Error::Conversion(format!(
+                "'{value}' can't be distinguished from the null string"
+            ))),
+        }
+    }
+
+    fn escape_text(&mut self, value: &str, escaped: Option<usize>) {
+        for (position, byte) in value.bytes().enumerate() {
+            match byte {
+                b'\\' => self.buffer.extend_from_slice(b"\\\\"),
+                b'\n' => self.buffer.extend_from_slice(b"\\n"),
+                b'\r' => self.buffer.extend_from_slice(b"\\r"),
+                b'\t' => self.buffer.extend_from_slice(b"\\t"),
+                byte if byte == self.options.delimiter || escaped == Some(position) => {
+                    self.buffer.extend_from_slice(&[b'\\', byte])
+                }
+                byte => self.buffer.push(byte),
+            }
+        }
+    }
+
+    fn write_csv(&mut self, column: usize, value: &str, header: bool) {
+        let options = &self.options;
+        let bytes = value.as_bytes();
+
+        let forced = !header && (options.force_quote_all || options.force_quote.contains(&column));
+        let quoted = forced
+            || value == options.null
+            || value.starts_with("\\.")
+            || bytes
+                .iter()
+                .any(|x| [options.delimiter, options.quote, b'\n', b'\r'].contains(x));
+
+        if !quoted {
+            self.buffer.extend_from_slice(bytes);
+            return;
+        }
+
+        self.buffer.push(options.quote);
+
+        for byte in bytes {
+            if *byte == options.quote || *byte == options.escape {
+                self.buffer.push(options.escape);
+            }
+
+            self.buffer.push(*byte);
+        }
+
+        self.buffer.push(options.quote);
+    }
+
+    /**
+     * Returns the encoded data and empties the buffer, to send rows by batches.
+     */
+    pub fn take(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.buffer)
+    }
+
+    /**
+     * Returns the remaining data.
+     */
+    pub fn finish(self) -> Vec<u8> {
+        self.buffer
+    }
+
+    /**
+     * Returns the size of the buffered data.
+     */
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /**
+     * Returns `true` if there is no buffered data.
+     */
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+}
+
+/**
+ * A row decoded by a `Decoder`.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Row {
+    types: std::sync::Arc<[crate::Type]>,
+    fields: Vec<Option<String>>,
+}
+
+impl Row {
+    /**
+     * Returns the number of fields.
+     */
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /**
+     * Returns `true` if the row has no field.
+     */
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /**
+     * Returns the value of a field, `None` if it is `NULL`.
+     */
+    pub fn raw(&self, index: usize) -> Option<&str> {
+        self.fields.get(index).and_then(Option::as_deref)
+    }
+
+    /**
+     * Converts a field to a rust type, from the type given to the decoder or `text`.
+     */
+    pub fn get<'a, T: crate::types::FromSql<'a>>(
+        &'a self,
+        index: usize,
+    ) -> crate::errors::Result<T> {
+        let Some(field) = self.fields.get(index) else {
+            return Err(crate::errors::Error::ColumnNotFound(index.to_string()));
+        };
+        let ty = self.types.get(index).unwrap_or(&crate::types::TEXT);
+
+        T::from_sql(ty, crate::Format::Text, field.as_deref().map(str::as_bytes))
+    }
+
+    /**
+     * Returns the fields.
+     */
+    pub fn into_fields(self) -> Vec<Option<String>> {
+        self.fields
+    }
+}
+
+/**
+ * Decodes a text or CSV `COPY` stream, fed with the data of `libpq::Connection::copy_data` or
+ * read from a `libpq::copy::CopyOutReader`.
+ *
+ * # Examples
+ *
+ * ```
+ * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+ * let conn = libpq::Connection::new(&dsn).unwrap();
+ * let result = conn.exec("COPY (SELECT 1, E'a\\tb', NULL) TO STDOUT");
+ * assert_eq!(result.status(), libpq::Status::CopyOut);
+ *
+ * let options = libpq::copy::text::Options::text();
+ * let types = [libpq::types::INT4, libpq::types::TEXT, libpq::types::TEXT];
+ * let mut decoder = libpq::copy::text::Decoder::new(&types, options);
+ * while let Ok(data) = conn.copy_data(false) {
+ *     decoder.feed(&data);
+ * }
+ *
+ * let row = decoder.next_row().unwrap().unwrap();
+ * assert_eq!(row.get::<i32>(0), Ok(1));
+ * assert_eq!(row.raw(1), Some("a\tb"));
+ * assert_eq!(row.raw(2), None);
+ * ```
+ */
+#[derive(Clone, Debug)]
+pub struct Decoder {
+    types: std::sync::Arc<[crate::Type]>,
+    options: Options,
+    buffer: Vec<u8>,
+    // Start of the data not decoded yet.
+    pos: usize,
+    header: bool,
+}
+
+impl Decoder {
+    /**
+     * Creates a decoder, rows must have as many fields as `types` if it isn't empty.
+     */
+    pub fn new(types: &[crate::Type], options: Options) -> Self {
+        Self {
+            types: types.into(),
+            header: !options.header,
+            options,
+            buffer: Vec::new(),
+            pos: 0,
+        }
+    }
+
+    /**
+     * Appends data to decode.
+     */
+    pub fn feed(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    /**
+     * Decodes the next row, returns `None` if more data is needed.
+     */
+    pub fn next_row(&mut self) -> crate::errors::Result<Option<Row>> {
+        loop {
+            let Some(end) = self.line_end() else {
+                return Ok(None);
+            };
+
+            let start = self.pos;
+            let mut line = &self.buffer[start..end];
+
+            if line.last() == Some(&b'\r') {
+                line = &line[..line.len() - 1];
+            }
+
+            let fields = if !self.header {
+                None
+            } else if self.options.csv {
+                Some(self.parse_csv(line))
+            } else {
+                Some(self.parse_text(line))
+            };
+
+            self.consume(end + 1 - start);
+
+            let Some(fields) = fields else {
+                self.header = true;
+                continue;
+            };
+            let fields = fields?;
+
+            if !self.types.is_empty() && fields.len() != self.types.len() {
+                return Err(Self::error(&format!(
+                    "expected {} fields, got {}",
+                    self.types.len(),
+                    fields.len()
+                )));
+            }
+
+            return Ok(Some(Row {
+                types: self.types.clone(),
+                fields,
+            }));
+        }
+    }
+
+    /**
+     * Returns the position of the next line end, the CSV quoted values may contain new lines.
+     */
+    fn line_end(&self) -> Option<usize> {
+        if !self.options.csv {
+            return self.buffer[self.pos..]
+                .iter()
+                .position(|x| *x == b'\n')
+                .map(|x| self.pos + x);
+        }
+
+        let Options { quote, escape, .. } = self.options;
+        let mut quoted = false;
+        let mut pos = self.pos;
+
+        while pos < self.buffer.len() {
+            let byte = self.buffer[pos];
+            let next = self.buffer.get(pos + 1).copied();
+
+            if quoted {
+                if byte == escape && escape != quote {
+                    let next = next?;
+
+                    if next == quote || next == escape {
+                        pos += 1;
+                    }
+                } else if byte == quote {
+                    match next {
+                        None => return None,
+                        Some(next) if next == quote && escape == quote => pos += 1,
+                        Some(_) => quoted = false,
+                    }
+                }
+            } else if byte == quote {
+                quoted = true;
+            } else if byte == b'\n' {
+                return Some(pos);
+            }
+
+            pos += 1;
+        }
+
+        None
+    }
+
+    /**
+     * Skips decoded data, the buffer is only compacted once most of it is decoded to keep
+     * decoding linear.
+     */
+    fn consume(&mut self, len: usize) {
+        self.pos += len;
+
+        if self.pos * 2 > self.buffer.len() {
+            self.buffer.drain(..self.pos);
+            self.pos = 0;
+        }
+    }
+
+    fn parse_text(&self, line: &[u8]) -> crate::errors::Result<Vec<Option<String>>> {
+        let mut fields = Vec::new();
+        let mut start = 0;
+        let mut pos = 0;
+
+        while pos <= line.len() {
+            match line.get(pos) {
+                Some(b'\\') => pos += 2,
+                Some(byte) if *byte != self.options.delimiter => pos += 1,
+                _ => {
+                    let raw = &line[start..pos.min(line.len())];
+
+                    if raw == self.options.null.as_bytes() {
+                        fields.push(None);
+                    } else {
+                        fields.push(Some(std::str::from_utf8(&Self::unescape(raw))?.to_string()));
+                    }
+
+                    pos += 1;
+                    start = pos;
+                }
+            }
+        }
+
+        Ok(fields)
+    }
+
+    fn unescape(raw: &[u8]) -> Vec<u8> {
+        let mut value = Vec::with_capacity(raw.len());
+        let mut bytes = raw.iter().copied().peekable();
+
+        while let Some(byte) = bytes.next() {
+            if byte != b'\\' {
+                value.push(byte);
+                continue;
+            }
+
+            let Some(byte) = bytes.next() else {
+                value.push(b'\\');
+                break;
+            };
+
+            match byte {
+                b'b' => value.push(0x08),
+                b'f' => value.push(0x0C),
+                b'n' => value.push(b'\n'),
+                b'r' => value.push(b'\r'),
+                b't' => value.push(b'\t'),
+                b'v' => value.push(0x0B),
+                b'0'..=b'7' => {
+                    let mut code = byte - b'0';
+
+                    for _ in 0..2 {
+                        match bytes.next_if(|x| (b'0'..=b'7').contains(x)) {
+                            Some(digit) => code = code.wrapping_mul(8) + (digit - b'0'),
+                            None => break,
+                        }
+                    }
+
+                    value.push(code);
+                }
+                b'x' if bytes.peek().is_some_and(u8::is_ascii_hexdigit) => {
+                    let mut code = 0;
+
+                    for _ in 0..2 {
+                        match bytes.next_if(u8::is_ascii_hexdigit) {
+                            Some(digit) => {
+                                code = code * 16 + (digit as char).to_digit(16).unwrap() as u8
+                            }
+                            None => break,
+                        }
+                    }
+
+                    value.push(code);
+                }
+                byte => value.push(byte),
+            }
+        }
+
+        value
+    }
+
+    fn parse_csv(&self, line: &[u8]) -> crate::errors::Result<Vec<Option<String>>> {
+        let Options {
+            delimiter,
+            quote,
+            escape,
+            ..
+        } = self.options;
+        let mut fields = Vec::new();
+        let mut pos = 0;
+
+        loop {
+            let column = fields.len();
+            let mut value = Vec::new();
+            let mut quoted = false;
+
+            while pos < line.len() && line[pos] != delimiter {
+                if line[pos] != quote {
+                    value.push(line[pos]);
+                    pos += 1;
+                    continue;
+                }
+
+                quoted = true;
+                pos += 1;
+
+                loop {
+                    let Some(byte) = line.get(pos) else {
+                        return Err(Self::error("unterminated CSV quoted field"));
+                    };
+                    let next = line.get(pos + 1);
+
+                    if *byte == escape && (next == Some(&quote) || next == Some(&escape)) {
+                        value.push(*next.unwrap());
+                        pos += 2;
+                    } else if *byte == quote {
+                        pos += 1;
+                        break;
+                    } else {
+                        value.push(*byte);
+                        pos += 1;
+                    }
+                }
+            }
+
+            let is_null = value == self.options.null.as_bytes()
+                && if quoted {
+                    self.options.force_null.contains(&column)
+                } else {
+                    !self.options.force_not_null.contains(&column)
+                };
+
+            if is_null {
+                fields.push(None);
+            } else {
+                fields.push(Some(std::str::from_utf8(&value)?.to_string()));
+            }
+
+            if pos >= line.len() {
+                break;
+            }
+
+            pos += 1;
+        }
+
+        Ok(fields)
+    }
+
+    fn error(message: &str) -> crate::errors::Error {
+        crate::errors::Error::Conversion(format!("invalid COPY data: {message}"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{Read, Write};
+
+    fn decode(options: super::Options, data: &[u8]) -> Vec<Vec<Option<String>>> {
+        let mut decoder = super::Decoder::new(&[], options);
+        decoder.feed(data);
+
+        let mut rows = Vec::new();
+        while let Some(row) = decoder.next_row().unwrap() {
+            rows.push(row.into_fields());
+        }
+
+        rows
+    }
+
+    fn strings(row: &[Option<&str>]) -> Vec<Option<String>> {
+        row.iter().map(|x| x.map(ToString::to_string)).collect()
+    }
+
+    #[test]
+    fn options() {
+        assert_eq!(super::Options::text().to_string(), "FORMAT text");
+        assert_eq!(
+            super::Options::text()
+                .delimiter(b'|')
+                .null("it's null")
+                .to_string(),
+            "FORMAT text, DELIMITER '|', NULL 'it''s null'"
+        );
+        assert_eq!(
+            super::Options::csv().quote(b'\'').escape(b'\\').to_string(),
+            "FORMAT csv, QUOTE '''', ESCAPE '\\'"
+        );
+    }
+
+    #[test]
+    fn text() {
+        let row = [
+            Some("tab\tnew\nline\\"),
+            None,
+            Some("\\N"),
+            Some(""),
+            Some("|"),
+        ];
+
+        let mut encoder = super::Encoder::new(super::Options::text());
+        encoder.write_row(&row).unwrap();
+        let data = encoder.finish();
+        assert_eq!(data, b"tab\\tnew\\nline\\\\\t\\N\t\\\\N\t\t|\n");
+        assert_eq!(decode(super::Options::text(), &data), [strings(&row)]);
+
+        let options = super::Options::text().delimiter(b'|').null("NULL");
+        let mut encoder = super::Encoder::new(options.clone());
+        encoder
+            .write_row(&[Some("NULL"), None, Some("a|b")])
+            .unwrap();
+        let data = encoder.finish();
+        assert_eq!(data, b"\\NULL|NULL|a\\|b\n");
+        assert_eq!(
+            decode(options, &data),
+            [strings(&[Some("NULL"), None, Some("a|b")])]
+        );
+
+        assert_eq!(
+            decode(super::Options::text(), b"\\101\\x42\\xz\\q\\0\n"),
+            [strings(&[Some("ABxzq\0")])]
+        );
+    }
+
+    #[test]
+    fn text_null_collision() {
+        let options = super::Options::text().null("");
+        let mut encoder = super::Encoder::new(options);
+        encoder.write_row(&[Some("a")]).unwrap();
+        assert!(encoder.write_row(&[Some("b"), Some("")]).is_err());
+        assert_eq!(encoder.finish(), b"a\n");
+
+        let options = super::Options::text().null("nan");
+        let mut encoder = super::Encoder::new(options.clone());
+        encoder.write_row(&[Some("nan"), None]).unwrap();
+        let data = encoder.finish();
+        assert_eq!(data, b"n\\an\tnan\n");
+        assert_eq!(decode(options, &data), [strings(&[Some("nan"), None])]);
+
+        let mut encoder = super::Encoder::new(super::Options::text().null("7"));
+        assert!(encoder.write_row(&[Some("7")]).is_err());
+    }
+
+    #[test]
+    fn text_null_collision_copy_in() {
+        let conn = crate::test::new_conn();
+        conn.try_exec("CREATE TEMPORARY TABLE tmp (id int, name text)")
+            .unwrap();
+
+        let options = super::Options::text().null("nan");
+        let mut encoder = super::Encoder::new(options.clone());
+        encoder.write_values(&[&1, &"nan"]).unwrap();
+        encoder.write_values(&[&2, &None::<String>]).unwrap();
+
+        let mut writer = conn
+            .copy_in(&format!("COPY tmp FROM STDIN ({options})"))
+            .unwrap();
+        writer.write_all(&encoder.finish()).unwrap();
+        writer.finish().unwrap();
+
+        let result = conn.try_exec("SELECT name FROM tmp ORDER BY id").unwrap();
+        assert_eq!(result.get::<Option<&str>>(0, 0), Ok(Some("nan")));
+        assert_eq!(result.get::<Option<&str>>(1, 0), Ok(None));
+    }
+
+    #[test]
+    fn csv() {
+        let row = [
+            Some("plain"),
+            Some("a,b"),
+            Some("say \"hi\""),
+            Some("multi\nline"),
+            Some(""),
+            None,
+        ];
+
+        let mut encoder = super::Encoder::new(super::Options::csv());
+        encoder.write_row(&row).unwrap();
+        let data = encoder.finish();
+        assert_eq!(
+            data,
+            b"plain,\"a,b\",\"say \"\"hi\"\"\",\"multi\nline\",\"\",\n"
+        );
+        assert_eq!(decode(super::Options::csv(), &data), [strings(&row)]);
+
+        let options = super::Options::csv()
+            .escape(b'\\')
+            .force_quote(&[0])
+            .header(true);
+        let mut encoder = super::Encoder::new(options.clone());
+        encoder.write_header(&["a", "b"]).unwrap();
+        encoder.write_row(&[Some("x"), Some("\\\"")]).unwrap();
+        let data = encoder.finish();
+        assert_eq!(data, b"a,b\n\"x\",\"\\\\\\\"\"\n");
+        assert_eq!(
+            decode(options, &data),
+            [strings(&[Some("x"), Some("\\\"")])]
+        );
+    }
+
+    #[test]
+    fn csv_force_null() {
+        let data = b",\"\"\n";
+
+        assert_eq!(
+            decode(super::Options::csv(), data),
+            [strings(&[None, Some("")])]
+        );
+        assert_eq!(
+            decode(super::Options::csv().force_not_null(&[0]), data),
+            [strings(&[Some(""), Some("")])]
+        );
+        assert_eq!(
+            decode(super::Options::csv().force_null(&[1]), data),
+            [strings(&[None, None])]
+        );
+    }
+
+    #[test]
+    fn partial() {
+        let mut decoder = super::Decoder::new(&[], super::Options::csv());
+
+        for byte in b"\"a\"\"\nb\",c\n" {
+            assert_eq!(decoder.next_row(), Ok(None));
+            decoder.feed(&[*byte]);
+        }
+
+        let row = decoder.next_row().unwrap().unwrap();
+        assert_eq!(row.raw(0), Some("a\"\nb"));
+        assert_eq!(row.raw(1), Some("c"));
+
+        let mut decoder = super::Decoder::new(&[], super::Options::csv());
+        decoder.feed(b"\"unterminated\n");
+        assert_eq!(decoder.next_row(), Ok(None));
+    }
+
+    #[test]
+    fn decode_chunks() {
+        for options in [super::Options::text(), super::Options::csv()] {
+            let mut encoder = super::Encoder::new(options.clone());
+            for id in 0..100_000 {
+                encoder.write_values(&[&id, &format!("row\n{id}")]).unwrap();
+            }
+            let data = encoder.finish();
+
+            let mut decoder =
+                super::Decoder::new(&[crate::types::INT4, crate::types::TEXT], options);
+            let mut count = 0;
+            for chunk in data.chunks(7) {
+                decoder.feed(chunk);
+
+                while let Some(row) = decoder.next_row().unwrap() {
+                    assert_eq!(row.get::<i32>(0), Ok(count));
+                    assert_eq!(row.get::<String>(1), Ok(format!("row\n{count}")));
+                    count += 1;
+                }
+            }
+
+            assert_eq!(count, 100_000);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let conn = crate::test::new_conn();
+        conn.try_exec("CREATE TEMPORARY TABLE tmp (id int, name text)")
+            .unwrap();
+
+        let values = [
+            "simple",
+            "tab\tand\nnew line",
+            "back\\slash",
+            "\"quoted\", comma",
+            "",
+            "nan",
+        ];
+
+        for options in [super::Options::text(), super::Options::csv().header(true)] {
+            conn.try_exec("TRUNCATE tmp").unwrap();
+
+            let mut encoder = super::Encoder::new(options.clone());
+            if options.header {
+                encoder.write_header(&["id", "name"]).unwrap();
+            }
+            for (id, value) in values.iter().enumerate() {
+                encoder.write_values(&[&(id as i32), value]).unwrap();
+            }
+            encoder.write_values(&[&-1, &None::<String>]).unwrap();
+
+            let mut writer = conn
+                .copy_in(&format!("COPY tmp FROM STDIN ({options})"))
+                .unwrap();
+            writer.write_all(&encoder.finish()).unwrap();
+            assert_eq!(writer.finish().unwrap().cmd_tuples(), Ok(7));
+
+            let mut reader = conn
+                .copy_out(&format!(
+                    "COPY (SELECT * FROM tmp ORDER BY id) TO STDOUT ({options})"
+                ))
+                .unwrap();
+            let mut data = Vec::new();
+            reader.read_to_end(&mut data).unwrap();
+
+            let mut decoder =
+                super::Decoder::new(&[crate::types::INT4, crate::types::TEXT], options);
+            decoder.feed(&data);
+
+            let row = decoder.next_row().unwrap().unwrap();
+            assert_eq!(row.get::<i32>(0), Ok(-1));
+            assert_eq!(row.get::<Option<&str>>(1), Ok(None));
+
+            for (id, value) in values.iter().enumerate() {
+                let row = decoder.next_row().unwrap().unwrap();
+                assert_eq!(row.get::<i32>(0), Ok(id as i32));
+                assert_eq!(row.get::<&str>(1), Ok(*value));
+            }
+
+            assert_eq!(decoder.next_row(), Ok(None));
+        }
+    }
+}
@@ -5,6 +5,8 @@ pub enum Error {
     #[error("{0}")]
     ParseIntError(#[from] std::num::ParseIntError),
     #[error("{0}")]
+    ParseFloatError(#[from] std::num::ParseFloatError),
+    #[error("{0}")]
     NulError(#[from] std::ffi::NulError),
     #[error("{0}")]
     Backend(String),
@@ -14,4 +16,161 @@ pub enum Error {
     Unknow,
     #[error("{0}")]
     Utf8(#[from] std::str::Utf8Error),
+    #[error("Unexpected null value")]
+    UnexpectedNull,
+    #[error("Cannot convert postgresql type '{ty}' from or to rust type '{rust}'")]
+    WrongType { ty: String, rust: &'static str },
+    #[error("Invalid value: {0}")]
+    Conversion(String),
+    #[error("Column '{0}' not found")]
+    ColumnNotFound(String),
+    #[error("{0}")]
+    DbError(Box<DbError>),
+    #[error("Invalid transaction status: {0:?}")]
+    TransactionStatus(crate::transaction::Status),
+    #[error("{1}")]
+    Io(std::io::ErrorKind, String),
+    #[error("Invalid option '{0}'")]
+    InvalidOption(String),
+    #[error("Timed out waiting for a pooled connection")]
+    PoolTimeout,
+    #[error("Query skipped by an aborted pipeline")]
+    PipelineAborted,
+    #[error("No pending request for this pipeline handle")]
+    PipelineHandle,
+    #[error("Listener task stopped")]
+    ListenerStopped,
+    #[error("Transaction failed after {attempts} attempts: {error}")]
+    Retry { attempts: u32, error: Box<Error> },
+    #[error("Row {row} of the batch failed: {error}")]
+    Batch { row: usize, error: Box<Error> },
+}
+
+impl Error {
+    /**
+     * Returns the database error, if this error was reported by the server.
+     */
+    pub fn db_error(&self) -> Option<&DbError> {
+        match self {
+            Self::DbError(error) => Some(error),
+            Self::Retry { error, .. } | Self::Batch { error, .. } => error.db_error(),
+            _ => None,
+        }
+    }
+
+    /**
+     * Returns the SQLSTATE of the database error, if any.
+     */
+    pub fn state(&self) -> Option<&crate::State> {
+        self.db_error().map(|x| &x.state)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error.kind(), error.to_string())
+    }
+}
+
+impl From<DbError> for Error {
+    fn from(error: DbError) -> Self {
+        Self::DbError(Box::new(error))
+    }
+}
+
+/**
+ * An error reported by the server, with all the fields of the error report.
+ *
+ * See [Error Message Fields](https://www.postgresql.org/docs/current/protocol-error-fields.html).
+ */
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[error("{severity}: {message}")]
+pub struct DbError {
+    /** The localized severity. */
+    pub severity: String,
+    /** The severity, never localized. */
+    pub severity_nonlocalized: Option<String>,
+    /** The SQLSTATE code for the error, as sent by the server. */
+    pub code: String,
+    /**
+     * The state matching `code`, see `libpq::State::from_code` for codes unknown to this
+     * crate.
+     */
+    pub state: crate::State,
+    /** The primary human-readable error message. */
+    pub message: String,
+    /** An optional secondary error message carrying more detail about the problem. */
+    pub detail: Option<String>,
+    /** An optional suggestion what to do about the problem. */
+    pub hint: Option<String>,
+    /** The error cursor position as an index into the original statement string. */
+    pub position: Option<usize>,
+    /** The error cursor position as an index into `internal_query`. */
+    pub internal_position: Option<usize>,
+    /** The text of a failed internally-generated command. */
+    pub internal_query: Option<String>,
+    /** An indication of the context in which the error occurred. */
+    pub context: Option<String>,
+    /** The name of the schema containing the object associated with the error. */
+    pub schema: Option<String>,
+    /** The name of the table associated with the error. */
+    pub table: Option<String>,
+    /** The name of the table column associated with the error. */
+    pub column: Option<String>,
+    /** The name of the data type associated with the error. */
+    pub datatype: Option<String>,
+    /** The name of the constraint associated with the error. */
+    pub constraint: Option<String>,
+    /** The file name of the source-code location where the error was reported. */
+    pub file: Option<String>,
+    /** The line number of the source-code location where the error was reported. */
+    pub line: Option<usize>,
+    /** The name of the source-code function reporting the error. */
+    pub routine: Option<String>,
+}
+
+impl DbError {
+    /**
+     * Extracts the error fields of a result, returns `None` if the result doesn't carry a
+     * SQLSTATE.
+     */
+    pub fn from_result(result: &crate::PQResult) -> Option<Self> {
+        use crate::result::ErrorField;
+
+        let field = |field| {
+            result
+                .error_field(field)
+                .ok()
+                .flatten()
+                .map(ToString::to_string)
+        };
+        let number = |f| field(f).and_then(|x| x.parse().ok());
+
+        let code = field(ErrorField::Sqlstate)?;
+        let state = crate::State::from_code(&code);
+
+        let error = Self {
+            severity: field(ErrorField::Severity).unwrap_or_default(),
+            severity_nonlocalized: field(ErrorField::SeverityNonlocalized),
+            code,
+            state,
+            message: field(ErrorField::MessagePrimary).unwrap_or_default(),
+            detail: field(ErrorField::MessageDetail),
+            hint: field(ErrorField::MessageHint),
+            position: number(ErrorField::StatementPosition),
+            internal_position: number(ErrorField::InternalPosition),
+            internal_query: field(ErrorField::InternalQuery),
+            context: field(ErrorField::Context),
+            schema: field(ErrorField::SchemaName),
+            table: field(ErrorField::TableName),
+            column: field(ErrorField::ColumnName),
+            datatype: field(ErrorField::DatatypeName),
+            constraint: field(ErrorField::ConstraintName),
+            file: field(ErrorField::SourceFile),
+            line: number(ErrorField::SourceLine),
+            routine: field(ErrorField::SourceFunction),
+        };
+
+        Some(error)
+    }
 }
@@ -4,14 +4,18 @@
 mod ffi;
 
 pub mod connection;
+pub mod copy;
 pub mod encrypt;
 pub mod errors;
 pub mod escape;
+#[cfg(unix)]
+pub mod listener;
 pub mod lo;
 pub mod ping;
 #[cfg(feature = "v14")]
 pub mod pipeline;
 pub mod poll;
+pub mod pool;
 #[cfg(unix)]
 pub mod print;
 pub mod result;
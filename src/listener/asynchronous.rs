@@ -0,0 +1,352 @@
+use ::tokio::sync::{mpsc, oneshot};
+
+type Sender = mpsc::UnboundedSender<crate::connection::Notification>;
+
+#[derive(Debug)]
+enum Command {
+    Subscribe {
+        channel: String,
+        id: u64,
+        sender: Sender,
+        reply: oneshot::Sender<crate::errors::Result>,
+    },
+    Unsubscribe {
+        channel: String,
+        id: u64,
+    },
+}
+
+/**
+ * Receives notifications in the tokio runtime, and fans them out to subscribers by channel.
+ *
+ * The connection is owned by a task spawned on the current runtime. It stops when the listener
+ * and all its subscriptions are dropped, or when the connection fails: the subscriptions then end.
+ *
+ * # Examples
+ *
+ * ```
+ * # #[tokio::main(flavor = "current_thread")]
+ * # async fn main() {
+ * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+ * let listener = libpq::listener::AsyncListener::connect(&dsn).await.unwrap();
+ * let mut jobs = listener.subscribe("jobs").await.unwrap();
+ *
+ * let conn = libpq::Connection::new(&dsn).unwrap();
+ * conn.notify("jobs", "42").unwrap();
+ *
+ * let notification = jobs.recv().await.unwrap();
+ * assert_eq!(notification.payload, "42");
+ * # }
+ * ```
+ */
+#[derive(Clone, Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub struct AsyncListener {
+    commands: mpsc::UnboundedSender<Command>,
+    ids: std::sync::Arc<std::sync::atomic::AtomicU64>,
+}
+
+impl AsyncListener {
+    /**
+     * Makes a new connection dedicated to the listener.
+     */
+    pub async fn connect(dsn: &str) -> crate::errors::Result<Self> {
+        let conn = crate::connection::AsyncConnection::connect(dsn).await?;
+
+        Ok(Self::new(conn))
+    }
+
+    /**
+     * Spawns the task driving `conn`, which shouldn't have pending commands.
+     */
+    pub fn new(conn: crate::connection::AsyncConnection) -> Self {
+        let (commands, receiver) = mpsc::unbounded_channel();
+
+        ::tokio::spawn(async move {
+            if let Err(err) = Driver::new(conn, receiver).run().await {
+                log::error!("Listener stopped: {err}");
+            }
+        });
+
+        Self {
+            commands,
+            ids: Default::default(),
+        }
+    }
+
+    /**
+     * Subscribes to `channel`, the channel is listened until all its subscriptions are dropped.
+     *
+     * Fails with `libpq::errors::Error::ListenerStopped` if the listener task ended.
+     *
+     * See [LISTEN](https://www.postgresql.org/docs/current/sql-listen.html).
+     */
+    pub async fn subscribe(&self, channel: &str) -> crate::errors::Result<Subscription> {
+        let id = self.ids.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let (reply, result) = oneshot::channel();
+
+        self.commands
+            .send(Command::Subscribe {
+                channel: channel.to_string(),
+                id,
+                sender,
+                reply,
+            })
+            .map_err(|_| crate::errors::Error::ListenerStopped)?;
+
+        result
+            .await
+            .map_err(|_| crate::errors::Error::ListenerStopped)??;
+
+        Ok(Subscription {
+            channel: channel.to_string(),
+            id,
+            receiver,
+            commands: self.commands.clone(),
+        })
+    }
+}
+
+/**
+ * A stream of the notifications of a channel, see `AsyncListener::subscribe`.
+ */
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub struct Subscription {
+    channel: String,
+    id: u64,
+    receiver: mpsc::UnboundedReceiver<crate::connection::Notification>,
+    commands: mpsc::UnboundedSender<Command>,
+}
+
+impl Subscription {
+    /**
+     * Returns the subscribed channel.
+     */
+    pub fn channel(&self) -> &str {
+        &self.channel
+    }
+
+    /**
+     * Waits for the next notification, returns `None` once the listener stopped.
+     */
+    pub async fn recv(&mut self) -> Option<crate::connection::Notification> {
+        self.receiver.recv().await
+    }
+}
+
+impl futures_core::Stream for Subscription {
+    type Item = crate::connection::Notification;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        // The listener may already be stopped.
+        self.commands
+            .send(Command::Unsubscribe {
+                channel: std::mem::take(&mut self.channel),
+                id: self.id,
+            })
+            .ok();
+    }
+}
+
+struct Driver {
+    conn: crate::connection::AsyncConnection,
+    commands: mpsc::UnboundedReceiver<Command>,
+    subscribers: std::collections::HashMap<String, Vec<(u64, Sender)>>,
+}
+
+impl Driver {
+    fn new(
+        conn: crate::connection::AsyncConnection,
+        commands: mpsc::UnboundedReceiver<Command>,
+    ) -> Self {
+        Self {
+            conn,
+            commands,
+            subscribers: Default::default(),
+        }
+    }
+
+    async fn run(mut self) -> crate::errors::Result {
+        loop {
+            ::tokio::select! {
+                command = self.commands.recv() => match command {
+                    Some(command) => self.handle(command).await?,
+                    None => return Ok(()),
+                },
+                result = self.conn.consume_input() => result?,
+            }
+
+            self.dispatch()?;
+        }
+    }
+
+    async fn handle(&mut self, command: Command) -> crate::errors::Result {
+        match command {
+            Command::Subscribe {
+                channel,
+                id,
+                sender,
+                reply,
+            } => {
+                if !self.subscribers.contains_key(&channel) {
+                    let result = self.exec("LISTEN", &channel).await;
+                    let failed = result.is_err();
+
+                    reply.send(result).ok();
+
+                    if failed {
+                        return self.check();
+                    }
+                } else {
+                    reply.send(Ok(())).ok();
+                }
+
+                self.subscribers
+                    .entry(channel)
+                    .or_default()
+                    .push((id, sender));
+            }
+            Command::Unsubscribe { channel, id } => {
+                let Some(senders) = self.subscribers.get_mut(&channel) else {
+                    return Ok(());
+                };
+
+                senders.retain(|(x, _)| *x != id);
+
+                if senders.is_empty() {
+                    self.subscribers.remove(&channel);
+                    self.exec("UNLISTEN", &channel).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn exec(&mut self, command: &str, channel: &str) -> crate::errors::Result {
+        let channel = self
+            .conn
+            .connection()
+            .escape_identifier(channel)?
+            .to_str()?
+            .to_string();
+
+        let result = self.conn.exec(&format!("{command} {channel}")).await?;
+        self.conn.connection().check(result)?;
+
+        Ok(())
+    }
+
+    /**
+     * A failed command only stops the driver if the connection is broken.
+     */
+    fn check(&self) -> crate::errors::Result {
+        match self.conn.connection().status() {
+            crate::connection::Status::Ok => Ok(()),
+            _ => self.conn.connection().error(),
+        }
+    }
+
+    fn dispatch(&mut self) -> crate::errors::Result {
+        while let Some(notify) = self.conn.connection().notifies() {
+            let notification = crate::connection::Notification::try_from(notify)?;
+
+            if let Some(senders) = self.subscribers.get(&notification.channel) {
+                for (_, sender) in senders {
+                    // A dropped subscription is removed by its `Unsubscribe` command.
+                    sender.send(notification.clone()).ok();
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    async fn listener() -> super::AsyncListener {
+        super::AsyncListener::connect(&crate::test::dsn())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn subscribe() {
+        let listener = listener().await;
+        let mut first = listener.subscribe("async").await.unwrap();
+        let mut second = listener.subscribe("async").await.unwrap();
+        let mut other = listener.subscribe("async other").await.unwrap();
+        assert_eq!(other.channel(), "async other");
+
+        let conn = crate::test::new_conn();
+        conn.notify("async", "payload").unwrap();
+        conn.notify("async other", "other").unwrap();
+
+        let notification = first.recv().await.unwrap();
+        assert_eq!(notification.channel, "async");
+        assert_eq!(notification.payload, "payload");
+        assert_eq!(notification.pid, conn.backend_pid());
+        assert_eq!(second.recv().await, Some(notification));
+
+        assert_eq!(other.recv().await.unwrap().payload, "other");
+    }
+
+    #[tokio::test]
+    async fn unsubscribe() {
+        let listener = listener().await;
+        let first = listener.subscribe("async unsubscribe").await.unwrap();
+        let mut second = listener.subscribe("async unsubscribe").await.unwrap();
+        drop(first);
+
+        let conn = crate::test::new_conn();
+        conn.notify("async unsubscribe", "still listened").unwrap();
+
+        assert_eq!(second.recv().await.unwrap().payload, "still listened");
+    }
+
+    #[tokio::test]
+    async fn stream() {
+        let listener = listener().await;
+        let mut subscription = listener.subscribe("async stream").await.unwrap();
+
+        let conn = crate::test::new_conn();
+        conn.notify("async stream", "streamed").unwrap();
+
+        let notification = std::future::poll_fn(|cx| {
+            futures_core::Stream::poll_next(std::pin::Pin::new(&mut subscription), cx)
+        })
+        .await
+        .unwrap();
+        assert_eq!(notification.payload, "streamed");
+    }
+
+    #[tokio::test]
+    async fn stopped() {
+        let conn = crate::connection::AsyncConnection::connect(&crate::test::dsn())
+            .await
+            .unwrap();
+        let pid = conn.connection().backend_pid();
+
+        let listener = super::AsyncListener::new(conn);
+        let mut subscription = listener.subscribe("async stopped").await.unwrap();
+
+        crate::test::new_conn()
+            .try_exec(&format!("SELECT pg_terminate_backend({pid})"))
+            .unwrap();
+
+        assert_eq!(subscription.recv().await, None);
+        assert!(listener.subscribe("async stopped").await.is_err());
+    }
+}
@@ -0,0 +1,341 @@
+#[cfg(feature = "tokio")]
+mod asynchronous;
+
+#[cfg(feature = "tokio")]
+pub use asynchronous::*;
+
+/**
+ * An event received by a `Listener`.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Event {
+    /** A notification on one of the listened channels. */
+    Notification(crate::connection::Notification),
+    /**
+     * The connection was lost and reset, all channels are listened again.
+     *
+     * Notifications sent while the connection was down are lost.
+     */
+    Reconnected,
+}
+
+/**
+ * Receives notifications from a set of channels.
+ *
+ * See [Asynchronous Notification](https://www.postgresql.org/docs/current/libpq-notify.html).
+ *
+ * # Examples
+ *
+ * ```
+ * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+ * let mut listener = libpq::listener::Listener::connect(&dsn).unwrap();
+ * listener.listen("jobs").unwrap();
+ *
+ * let conn = libpq::Connection::new(&dsn).unwrap();
+ * conn.notify("jobs", "42").unwrap();
+ *
+ * let event = listener
+ *     .recv_timeout(std::time::Duration::from_secs(1))
+ *     .unwrap();
+ *
+ * match event {
+ *     Some(libpq::listener::Event::Notification(notification)) => {
+ *         assert_eq!(notification.payload, "42")
+ *     }
+ *     _ => unreachable!(),
+ * }
+ * ```
+ */
+#[derive(Debug)]
+pub struct Listener {
+    conn: crate::Connection,
+    channels: std::collections::BTreeSet<String>,
+}
+
+impl Listener {
+    /**
+     * Makes a new connection dedicated to the listener.
+     */
+    pub fn connect(dsn: &str) -> crate::errors::Result<Self> {
+        let conn = crate::Connection::new(dsn)?;
+
+        if conn.status() != crate::connection::Status::Ok {
+            return conn.error();
+        }
+
+        Ok(Self::new(conn))
+    }
+
+    /**
+     * Uses an established connection, which shouldn't be shared with other work: notifications
+     * are only received between commands.
+     */
+    pub fn new(conn: crate::Connection) -> Self {
+        Self {
+            conn,
+            channels: Default::default(),
+        }
+    }
+
+    /**
+     * Returns the underlying connection.
+     */
+    pub fn connection(&self) -> &crate::Connection {
+        &self.conn
+    }
+
+    /**
+     * Returns the listened channels.
+     */
+    pub fn channels(&self) -> impl Iterator<Item = &str> {
+        self.channels.iter().map(String::as_str)
+    }
+
+    /**
+     * Starts listening on `channel`.
+     *
+     * See [LISTEN](https://www.postgresql.org/docs/current/sql-listen.html).
+     */
+    pub fn listen(&mut self, channel: &str) -> crate::errors::Result {
+        self.exec("LISTEN", channel)?;
+        self.channels.insert(channel.to_string());
+
+        Ok(())
+    }
+
+    /**
+     * Stops listening on `channel`.
+     *
+     * See [UNLISTEN](https://www.postgresql.org/docs/current/sql-unlisten.html).
+     */
+    pub fn unlisten(&mut self, channel: &str) -> crate::errors::Result {
+        self.exec("UNLISTEN", channel)?;
+        self.channels.remove(channel);
+
+        Ok(())
+    }
+
+    /**
+     * Stops listening on all channels.
+     */
+    pub fn unlisten_all(&mut self) -> crate::errors::Result {
+        self.conn.try_exec("UNLISTEN *")?;
+        self.channels.clear();
+
+        Ok(())
+    }
+
+    /**
+     * Returns the next event if one was already received, without blocking.
+     *
+     * If the connection was lost, it is reset and `Event::Reconnected` is returned.
+     */
+    pub fn try_recv(&mut self) -> crate::errors::Result<Option<Event>> {
+        if let Some(notify) = self.conn.notifies() {
+            return Ok(Some(Event::Notification(notify.try_into()?)));
+        }
+
+        if self.conn.status() != crate::connection::Status::Ok || self.conn.consume_input().is_err()
+        {
+            self.reconnect()?;
+            return Ok(Some(Event::Reconnected));
+        }
+
+        match self.conn.notifies() {
+            Some(notify) => Ok(Some(Event::Notification(notify.try_into()?))),
+            None => Ok(None),
+        }
+    }
+
+    /**
+     * Waits for the next event.
+     */
+    pub fn recv(&mut self) -> crate::errors::Result<Event> {
+        loop {
+            if let Some(event) = self.try_recv()? {
+                return Ok(event);
+            }
+
+            self.wait(None)?;
+        }
+    }
+
+    /**
+     * Waits for the next event, returns `None` if none is received before `timeout`.
+     */
+    pub fn recv_timeout(
+        &mut self,
+        timeout: std::time::Duration,
+    ) -> crate::errors::Result<Option<Event>> {
+        let deadline = std::time::Instant::now() + timeout;
+
+        loop {
+            if let Some(event) = self.try_recv()? {
+                return Ok(Some(event));
+            }
+
+            let timeout = deadline.saturating_duration_since(std::time::Instant::now());
+
+            if timeout.is_zero() {
+                return Ok(None);
+            }
+
+            self.wait(Some(timeout))?;
+        }
+    }
+
+    /**
+     * Returns a blocking iterator over the events, it never ends.
+     */
+    pub fn iter(&mut self) -> Iter<'_> {
+        Iter { listener: self }
+    }
+
+    fn exec(&self, command: &str, channel: &str) -> crate::errors::Result {
+        let channel = self.conn.escape_identifier(channel)?.to_str()?.to_string();
+
+        self.conn.try_exec(&format!("{command} {channel}"))?;
+
+        Ok(())
+    }
+
+    fn reconnect(&mut self) -> crate::errors::Result {
+        log::warn!("Listener connection lost, resetting");
+
+        self.conn.reset();
+
+        if self.conn.status() != crate::connection::Status::Ok {
+            return self.conn.error();
+        }
+
+        for channel in &self.channels {
+            self.exec("LISTEN", channel)?;
+        }
+
+        Ok(())
+    }
+
+    fn wait(&self, timeout: Option<std::time::Duration>) -> crate::errors::Result {
+        let mut fd = libc::pollfd {
+            fd: self.conn.socket()?,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let timeout = timeout.map_or(-1, |x| x.as_millis().clamp(1, i32::MAX as u128) as i32);
+
+        if unsafe { libc::poll(&mut fd, 1, timeout) } < 0 {
+            let err = std::io::Error::last_os_error();
+
+            if err.kind() != std::io::ErrorKind::Interrupted {
+                return Err(err.into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Listener {
+    type Item = crate::errors::Result<Event>;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/**
+ * Blocking iterator over the events of a `Listener`, see `Listener::iter`.
+ */
+#[derive(Debug)]
+pub struct Iter<'a> {
+    listener: &'a mut Listener,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = crate::errors::Result<Event>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.listener.recv())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    fn unwrap_notification(event: Option<super::Event>) -> crate::connection::Notification {
+        match event {
+            Some(super::Event::Notification(notification)) => notification,
+            event => panic!("Unexpected event {event:?}"),
+        }
+    }
+
+    #[test]
+    fn listen() {
+        let mut listener = super::Listener::connect(&crate::test::dsn()).unwrap();
+        listener.listen("listener \"quoted\"").unwrap();
+        listener.listen("other").unwrap();
+        assert_eq!(
+            listener.channels().collect::<Vec<_>>(),
+            ["listener \"quoted\"", "other"]
+        );
+
+        let conn = crate::test::new_conn();
+        conn.notify("listener \"quoted\"", "it's").unwrap();
+
+        let notification = unwrap_notification(
+            listener
+                .recv_timeout(std::time::Duration::from_secs(5))
+                .unwrap(),
+        );
+        assert_eq!(notification.channel, "listener \"quoted\"");
+        assert_eq!(notification.payload, "it's");
+        assert_eq!(notification.pid, conn.backend_pid());
+
+        listener.unlisten("other").unwrap();
+        conn.notify("other", "").unwrap();
+        conn.notify("listener \"quoted\"", "next").unwrap();
+
+        let notification = unwrap_notification(listener.iter().next().map(Result::unwrap));
+        assert_eq!(notification.payload, "next");
+    }
+
+    #[test]
+    fn timeout() {
+        let mut listener = super::Listener::connect(&crate::test::dsn()).unwrap();
+        listener.listen("timeout").unwrap();
+
+        let start = std::time::Instant::now();
+        let event = listener
+            .recv_timeout(std::time::Duration::from_millis(50))
+            .unwrap();
+
+        assert_eq!(event, None);
+        assert!(start.elapsed() >= std::time::Duration::from_millis(50));
+    }
+
+    #[test]
+    fn reconnect() {
+        let mut listener = super::Listener::connect(&crate::test::dsn()).unwrap();
+        listener.listen("reconnect").unwrap();
+
+        let conn = crate::test::new_conn();
+        let pid = listener.connection().backend_pid();
+        conn.try_exec(&format!("SELECT pg_terminate_backend({pid})"))
+            .unwrap();
+
+        let event = listener
+            .recv_timeout(std::time::Duration::from_secs(5))
+            .unwrap();
+        assert_eq!(event, Some(super::Event::Reconnected));
+        assert_ne!(listener.connection().backend_pid(), pid);
+
+        conn.notify("reconnect", "again").unwrap();
+
+        let notification = unwrap_notification(
+            listener
+                .recv_timeout(std::time::Duration::from_secs(5))
+                .unwrap(),
+        );
+        assert_eq!(notification.payload, "again");
+    }
+}
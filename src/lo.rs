@@ -1,4 +1,4 @@
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub struct LargeObject<'c> {
     fd: i32,
     conn: &'c crate::Connection,
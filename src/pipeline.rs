@@ -111,6 +111,368 @@ pub fn flush_request(conn: &crate::Connection) -> crate::errors::Result {
     }
 }
 
+/**
+ * Handle of a query queued in a `Pipeline`, resolved by `Pipeline::result`.
+ */
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "v14")))]
+pub struct Query(usize);
+
+impl Query {
+    /**
+     * Returns the position of the query in the pipeline.
+     */
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/**
+ * Handle of a synchronization point, resolved by `Pipeline::segment`.
+ */
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "v14")))]
+pub struct SyncPoint(usize);
+
+/**
+ * Queries between two synchronization points.
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "v14")))]
+pub struct Segment {
+    /** Index of the queries of the segment. */
+    pub queries: Vec<usize>,
+    /** Index of the query that aborted the segment. */
+    pub failed: Option<usize>,
+    /** Index of the queries skipped after the failure. */
+    pub skipped: Vec<usize>,
+}
+
+impl Segment {
+    /**
+     * Returns `true` if a query failed, the following ones of the segment are skipped.
+     */
+    pub fn is_aborted(&self) -> bool {
+        self.failed.is_some() || !self.skipped.is_empty()
+    }
+}
+
+#[derive(Debug)]
+enum Request {
+    Query(usize),
+    Sync(usize),
+}
+
+/**
+ * Queues queries in pipeline mode, and correlates them with their results.
+ *
+ * The connection is in nonblocking mode while the pipeline exists, the queries are sent as soon
+ * as the socket accepts them and the results read when requested: large batches don't block on a
+ * full server output buffer.
+ *
+ * See [Pipeline Mode](https://www.postgresql.org/docs/current/libpq-pipeline-mode.html).
+ *
+ * # Examples
+ *
+ * ```
+ * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+ * let conn = libpq::Connection::new(&dsn).unwrap();
+ * let mut pipeline = libpq::pipeline::Pipeline::new(&conn).unwrap();
+ *
+ * let one = pipeline.query("SELECT 1", &[]).unwrap();
+ * let error = pipeline.query("SELECT 1/0", &[]).unwrap();
+ * let skipped = pipeline.query("SELECT 3", &[]).unwrap();
+ * let sync = pipeline.sync().unwrap();
+ * let two = pipeline.query("SELECT $1::int4", &[&2]).unwrap();
+ *
+ * assert_eq!(pipeline.result(one).unwrap().value(0, 0), Some(&b"1"[..]));
+ * assert!(pipeline.result(error).is_err());
+ * assert_eq!(pipeline.result(skipped).unwrap_err(), libpq::errors::Error::PipelineAborted);
+ * assert_eq!(pipeline.segment(sync).unwrap().skipped, vec![2]);
+ * assert_eq!(pipeline.result(two).unwrap().value(0, 0), Some(&b"2"[..]));
+ *
+ * pipeline.finish().unwrap();
+ * ```
+ */
+#[cfg_attr(docsrs, doc(cfg(feature = "v14")))]
+pub struct Pipeline<'conn> {
+    conn: &'conn crate::Connection,
+    next: usize,
+    requests: std::collections::VecDeque<Request>,
+    results: std::collections::HashMap<usize, crate::errors::Result<crate::PQResult>>,
+    segments: std::collections::HashMap<usize, Segment>,
+    current: Segment,
+    unsynced: bool,
+    finished: bool,
+    non_blocking: bool,
+}
+
+impl<'conn> Pipeline<'conn> {
+    /**
+     * Enters pipeline mode, the connection must be idle.
+     *
+     * The connection is switched to nonblocking mode until the pipeline ends, its previous mode
+     * is restored then.
+     */
+    pub fn new(conn: &'conn crate::Connection) -> crate::errors::Result<Self> {
+        let non_blocking = conn.is_non_blocking();
+        enter(conn)?;
+
+        if let Err(err) = conn.set_non_blocking(true) {
+            exit(conn).ok();
+            return Err(err);
+        }
+
+        Ok(Self {
+            conn,
+            next: 0,
+            requests: Default::default(),
+            results: Default::default(),
+            segments: Default::default(),
+            current: Default::default(),
+            unsynced: false,
+            finished: false,
+            non_blocking,
+        })
+    }
+
+    /**
+     * Queues a query with parameters converted from rust values, see
+     * `libpq::Connection::send_query_with`.
+     */
+    pub fn query(
+        &mut self,
+        command: &str,
+        params: &[&dyn crate::types::ToSql],
+    ) -> crate::errors::Result<Query> {
+        let params = params
+            .iter()
+            .map(|x| crate::connection::Param::from_sql(*x))
+            .collect::<crate::errors::Result<Vec<_>>>()?;
+
+        self.query_with(command, &params, crate::Format::Text)
+    }
+
+    /**
+     * Queues a query, see `libpq::Connection::send_query_with`.
+     */
+    pub fn query_with(
+        &mut self,
+        command: &str,
+        params: &[crate::connection::Param],
+        result_format: crate::Format,
+    ) -> crate::errors::Result<Query> {
+        self.conn.send_query_with(command, params, result_format)?;
+
+        self.queued()
+    }
+
+    /**
+     * Queues the creation of a prepared statement, see `libpq::Connection::send_prepare`.
+     */
+    pub fn prepare(
+        &mut self,
+        name: Option<&str>,
+        query: &str,
+        param_types: &[crate::Oid],
+    ) -> crate::errors::Result<Query> {
+        self.conn.send_prepare(name, query, param_types)?;
+
+        self.queued()
+    }
+
+    /**
+     * Queues the execution of a prepared statement, see
+     * `libpq::Connection::send_query_prepared_with`.
+     */
+    pub fn query_prepared(
+        &mut self,
+        name: Option<&str>,
+        params: &[&dyn crate::types::ToSql],
+    ) -> crate::errors::Result<Query> {
+        let params = params
+            .iter()
+            .map(|x| crate::connection::Param::from_sql(*x))
+            .collect::<crate::errors::Result<Vec<_>>>()?;
+
+        self.query_prepared_with(name, &params, crate::Format::Text)
+    }
+
+    /**
+     * Queues the execution of a prepared statement, see
+     * `libpq::Connection::send_query_prepared_with`.
+     */
+    pub fn query_prepared_with(
+        &mut self,
+        name: Option<&str>,
+        params: &[crate::connection::Param],
+        result_format: crate::Format,
+    ) -> crate::errors::Result<Query> {
+        self.conn
+            .send_query_prepared_with(name, params, result_format)?;
+
+        self.queued()
+    }
+
+    /**
+     * Marks a synchronization point: a failed query skips the following ones until there.
+     */
+    pub fn sync(&mut self) -> crate::errors::Result<SyncPoint> {
+        sync(self.conn)?;
+
+        let index = self.next;
+        self.next += 1;
+        self.requests.push_back(Request::Sync(index));
+        self.unsynced = false;
+        self.flush()?;
+
+        Ok(SyncPoint(index))
+    }
+
+    /**
+     * Waits for the result of `query`.
+     *
+     * Returns `libpq::errors::Error::PipelineAborted` if the query was skipped after a previous
+     * failure.
+     */
+    pub fn result(&mut self, query: Query) -> crate::errors::Result<crate::PQResult> {
+        while !self.results.contains_key(&query.0) {
+            if self.unsynced {
+                // Asks the server to send the results without waiting for a sync.
+                flush_request(self.conn)?;
+                self.unsynced = false;
+                self.flush()?;
+            }
+
+            self.read()?;
+        }
+
+        self.results.remove(&query.0).unwrap()
+    }
+
+    /**
+     * Waits for the synchronization point, and returns the queries of the segment it ends.
+     */
+    pub fn segment(&mut self, sync: SyncPoint) -> crate::errors::Result<Segment> {
+        while !self.segments.contains_key(&sync.0) {
+            self.read()?;
+        }
+
+        Ok(self.segments.remove(&sync.0).unwrap())
+    }
+
+    /**
+     * Returns the number of queries and synchronization points whose result isn't read yet.
+     */
+    pub fn pending(&self) -> usize {
+        self.requests.len()
+    }
+
+    /**
+     * Synchronizes the queued queries, discards the unread results and exits pipeline mode.
+     */
+    pub fn finish(mut self) -> crate::errors::Result {
+        self.close()
+    }
+
+    fn queued(&mut self) -> crate::errors::Result<Query> {
+        let index = self.next;
+        self.next += 1;
+        self.requests.push_back(Request::Query(index));
+        self.unsynced = true;
+        self.flush()?;
+
+        Ok(Query(index))
+    }
+
+    /**
+     * Sends what the socket accepts, and reads the available input to let the server go on.
+     */
+    fn flush(&self) -> crate::errors::Result {
+        match unsafe { pq_sys::PQflush(self.conn.into()) } {
+            0 => Ok(()),
+            1 => self.conn.consume_input(),
+            _ => self.conn.error(),
+        }
+    }
+
+    fn read(&mut self) -> crate::errors::Result {
+        let Some(request) = self.requests.pop_front() else {
+            return Err(crate::errors::Error::PipelineHandle);
+        };
+
+        match request {
+            Request::Query(index) => {
+                let mut first = None;
+
+                // Each query results are followed by `None`.
+                while let Some(result) = self.conn.result() {
+                    if first.is_none() {
+                        first = Some(result);
+                    }
+                }
+
+                let result = match first {
+                    Some(result) if result.status() == crate::Status::PipelineAborted => {
+                        self.current.skipped.push(index);
+                        Err(crate::errors::Error::PipelineAborted)
+                    }
+                    Some(result) => self.conn.check(result),
+                    None => self.conn.error(),
+                };
+
+                if result.is_err() && self.current.failed.is_none() {
+                    self.current.failed = Some(index);
+                }
+
+                self.current.queries.push(index);
+                self.results.insert(index, result);
+            }
+            Request::Sync(index) => {
+                let result = self.conn.result();
+
+                if result.map(|x| x.status()) != Some(crate::Status::PipelineSync) {
+                    return self.conn.error();
+                }
+
+                let segment = std::mem::take(&mut self.current);
+                self.segments.insert(index, segment);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn close(&mut self) -> crate::errors::Result {
+        if self.finished {
+            return Ok(());
+        }
+
+        self.finished = true;
+
+        if self.unsynced {
+            sync(self.conn)?;
+            self.requests.push_back(Request::Sync(self.next));
+        }
+
+        while !self.requests.is_empty() {
+            self.read()?;
+        }
+
+        self.conn.set_non_blocking(self.non_blocking)?;
+
+        exit(self.conn)
+    }
+}
+
+impl Drop for Pipeline<'_> {
+    fn drop(&mut self) {
+        if let Err(err) = self.close() {
+            log::warn!("Failed to finish pipeline: {err}");
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     #[test]
@@ -155,4 +517,118 @@ mod test {
 
         assert!(crate::pipeline::flush_request(&conn).is_ok());
     }
+
+    #[test]
+    fn pipeline() {
+        let conn = crate::test::new_conn();
+        let mut pipeline = crate::pipeline::Pipeline::new(&conn).unwrap();
+        assert_eq!(crate::pipeline::status(&conn), crate::pipeline::Status::On);
+
+        let prepare = pipeline
+            .prepare(Some("pipeline"), "SELECT $1::int4 * 2", &[])
+            .unwrap();
+        let error = pipeline.query("SELECT 1/0", &[]).unwrap();
+        let skipped = pipeline.query_prepared(Some("pipeline"), &[&1]).unwrap();
+        let first = pipeline.sync().unwrap();
+        let prepared = pipeline.query_prepared(Some("pipeline"), &[&21]).unwrap();
+        let second = pipeline.sync().unwrap();
+        let unsynced = pipeline.query("SELECT 'unsynced'", &[]).unwrap();
+        assert_eq!(pipeline.pending(), 7);
+
+        // Results are read in order, but can be resolved in any order.
+        assert_eq!(
+            pipeline.result(prepared).unwrap().value(0, 0),
+            Some(&b"42"[..])
+        );
+        assert_eq!(
+            pipeline.segment(second).unwrap(),
+            crate::pipeline::Segment {
+                queries: vec![4],
+                failed: None,
+                skipped: Vec::new(),
+            }
+        );
+        assert_eq!(
+            pipeline.segment(first).unwrap(),
+            crate::pipeline::Segment {
+                queries: vec![0, 1, 2],
+                failed: Some(1),
+                skipped: vec![2],
+            }
+        );
+        assert!(pipeline.result(prepare).is_ok());
+        assert_eq!(
+            pipeline.result(error).unwrap_err().state(),
+            Some(&crate::state::DIVISION_BY_ZERO)
+        );
+        assert_eq!(
+            pipeline.result(skipped).unwrap_err(),
+            crate::errors::Error::PipelineAborted
+        );
+
+        assert_eq!(
+            pipeline.result(unsynced).unwrap().value(0, 0),
+            Some(&b"unsynced"[..])
+        );
+
+        pipeline.finish().unwrap();
+        assert_eq!(crate::pipeline::status(&conn), crate::pipeline::Status::Off);
+        assert!(!conn.is_non_blocking());
+        assert!(conn.try_exec("SELECT 1").is_ok());
+    }
+
+    #[test]
+    fn pipeline_non_blocking() {
+        let conn = crate::test::new_conn();
+        conn.set_non_blocking(true).unwrap();
+
+        let mut pipeline = crate::pipeline::Pipeline::new(&conn).unwrap();
+        assert!(pipeline.query("SELECT 1", &[]).is_ok());
+
+        // A handle of another pipeline.
+        let other_conn = crate::test::new_conn();
+        let mut other = crate::pipeline::Pipeline::new(&other_conn).unwrap();
+        assert!(other.query("SELECT 2", &[]).is_ok());
+        let query = other.query("SELECT 3", &[]).unwrap();
+        other.finish().unwrap();
+        assert_eq!(
+            pipeline.result(query).unwrap_err(),
+            crate::errors::Error::PipelineHandle
+        );
+
+        pipeline.finish().unwrap();
+        assert!(conn.is_non_blocking());
+        assert!(!other_conn.is_non_blocking());
+    }
+
+    #[test]
+    fn pipeline_large() {
+        let conn = crate::test::new_conn();
+        let mut pipeline = crate::pipeline::Pipeline::new(&conn).unwrap();
+
+        // Far more than the socket buffers in both directions.
+        let value = "x".repeat(10_000);
+        let queries = (0..1_000)
+            .map(|_| pipeline.query("SELECT $1::text", &[&value]).unwrap())
+            .collect::<Vec<_>>();
+        pipeline.sync().unwrap();
+
+        for query in queries {
+            let result = pipeline.result(query).unwrap();
+            assert_eq!(result.value(0, 0), Some(value.as_bytes()));
+        }
+
+        pipeline.finish().unwrap();
+    }
+
+    #[test]
+    fn pipeline_drop() {
+        let conn = crate::test::new_conn();
+        let mut pipeline = crate::pipeline::Pipeline::new(&conn).unwrap();
+        pipeline.query("SELECT 1", &[]).unwrap();
+        drop(pipeline);
+
+        assert_eq!(crate::pipeline::status(&conn), crate::pipeline::Status::Off);
+        assert!(conn.try_exec("SELECT 1").is_ok());
+    }
 }
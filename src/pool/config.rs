@@ -0,0 +1,125 @@
+pub type AfterConnect = dyn Fn(&crate::Connection) -> crate::errors::Result + Send + Sync;
+
+/**
+ * Pool configuration.
+ *
+ * # Examples
+ *
+ * ```
+ * let config = libpq::pool::Config::new("host=localhost")
+ *     .max_size(4)
+ *     .idle_timeout(Some(std::time::Duration::from_secs(60)))
+ *     .after_connect(|conn| conn.try_exec("SET application_name = 'pool'").map(|_| ()));
+ * ```
+ */
+#[derive(Clone)]
+pub struct Config {
+    pub(crate) dsn: String,
+    pub(crate) min_size: usize,
+    pub(crate) max_size: usize,
+    pub(crate) checkout_timeout: Option<std::time::Duration>,
+    pub(crate) max_lifetime: Option<std::time::Duration>,
+    pub(crate) idle_timeout: Option<std::time::Duration>,
+    pub(crate) reset: Option<String>,
+    pub(crate) after_connect: Option<std::sync::Arc<AfterConnect>>,
+}
+
+impl Config {
+    /**
+     * Creates a configuration for connections to `dsn`, see `libpq::Connection::new`.
+     */
+    pub fn new(dsn: &str) -> Self {
+        Self {
+            dsn: dsn.to_string(),
+            min_size: 0,
+            max_size: 10,
+            checkout_timeout: Some(std::time::Duration::from_secs(30)),
+            max_lifetime: Some(std::time::Duration::from_secs(30 * 60)),
+            idle_timeout: Some(std::time::Duration::from_secs(10 * 60)),
+            reset: Some("DISCARD ALL".to_string()),
+            after_connect: None,
+        }
+    }
+
+    /**
+     * Sets the number of connections opened on creation and kept while idle.
+     *
+     * Connections discarded later are not replaced until a checkout needs them.
+     */
+    pub fn min_size(mut self, min_size: usize) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /**
+     * Sets the maximum number of connections, idle or in use.
+     */
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size.max(1);
+        self
+    }
+
+    /**
+     * Sets how long `libpq::pool::Pool::get` waits for a connection, `None` waits forever.
+     */
+    pub fn checkout_timeout(mut self, checkout_timeout: Option<std::time::Duration>) -> Self {
+        self.checkout_timeout = checkout_timeout;
+        self
+    }
+
+    /**
+     * Sets the maximum lifetime of a connection, it is closed on its next check-in or checkout.
+     */
+    pub fn max_lifetime(mut self, max_lifetime: Option<std::time::Duration>) -> Self {
+        self.max_lifetime = max_lifetime;
+        self
+    }
+
+    /**
+     * Sets how long a connection stays idle before being closed, the pool keeps at least
+     * `min_size` connections.
+     *
+     * There is no background reaper: idle connections are only checked, and closed, when a
+     * checkout looks at them.
+     */
+    pub fn idle_timeout(mut self, idle_timeout: Option<std::time::Duration>) -> Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    /**
+     * Sets the SQL run on check-in to reset the session, `DISCARD ALL` by default.
+     *
+     * A connection is closed if its reset fails.
+     */
+    pub fn reset(mut self, reset: Option<&str>) -> Self {
+        self.reset = reset.map(ToString::to_string);
+        self
+    }
+
+    /**
+     * Sets a function called on each new connection, the connection is discarded if it fails.
+     */
+    pub fn after_connect<F>(mut self, after_connect: F) -> Self
+    where
+        F: Fn(&crate::Connection) -> crate::errors::Result + Send + Sync + 'static,
+    {
+        self.after_connect = Some(std::sync::Arc::new(after_connect));
+        self
+    }
+}
+
+impl std::fmt::Debug for Config {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Config")
+            .field("dsn", &self.dsn)
+            .field("min_size", &self.min_size)
+            .field("max_size", &self.max_size)
+            .field("checkout_timeout", &self.checkout_timeout)
+            .field("max_lifetime", &self.max_lifetime)
+            .field("idle_timeout", &self.idle_timeout)
+            .field("reset", &self.reset)
+            .field("after_connect", &self.after_connect.is_some())
+            .finish()
+    }
+}
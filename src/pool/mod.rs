@@ -0,0 +1,432 @@
+mod config;
+
+pub use config::*;
+
+/**
+ * Pool statistics, see `libpq::pool::Pool::stats`.
+ */
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    /** Connections checked out, or being opened. */
+    pub in_use: usize,
+    /** Connections available for checkout. */
+    pub idle: usize,
+    /** Number of checkouts that had to wait for a connection. */
+    pub waits: u64,
+    /** Number of connections that failed to open. */
+    pub creation_failures: u64,
+    /** Number of connections closed by the pool. */
+    pub discarded: u64,
+}
+
+#[derive(Debug)]
+struct Idle {
+    conn: crate::Connection,
+    created: std::time::Instant,
+    since: std::time::Instant,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    idle: std::collections::VecDeque<Idle>,
+    total: usize,
+    stats: Stats,
+}
+
+#[derive(Debug)]
+struct Inner {
+    config: Config,
+    state: std::sync::Mutex<State>,
+    available: std::sync::Condvar,
+}
+
+/**
+ * A blocking connection pool.
+ *
+ * The pool is cheap to clone, clones share the same connections.
+ *
+ * # Examples
+ *
+ * ```
+ * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+ * let pool = libpq::pool::Pool::new(libpq::pool::Config::new(&dsn).max_size(2)).unwrap();
+ *
+ * let conn = pool.get().unwrap();
+ * let result = conn.try_exec("SELECT 1").unwrap();
+ * assert_eq!(result.value(0, 0), Some(&b"1"[..]));
+ * ```
+ */
+#[derive(Clone, Debug)]
+pub struct Pool {
+    inner: std::sync::Arc<Inner>,
+}
+
+impl Pool {
+    /**
+     * Creates a pool and opens `min_size` connections.
+     */
+    pub fn new(config: Config) -> crate::errors::Result<Self> {
+        let pool = Self {
+            inner: std::sync::Arc::new(Inner {
+                config,
+                state: std::sync::Mutex::new(State::default()),
+                available: std::sync::Condvar::new(),
+            }),
+        };
+
+        for _ in 0..pool.inner.config.min_size {
+            pool.lock().total += 1;
+
+            let conn = pool.connect()?;
+            pool.checkin(conn, std::time::Instant::now());
+        }
+
+        Ok(pool)
+    }
+
+    /**
+     * Checks out a connection, opens a new one if none is idle and the pool isn't full.
+     *
+     * Returns `libpq::errors::Error::PoolTimeout` if no connection is available before the
+     * checkout timeout.
+     */
+    pub fn get(&self) -> crate::errors::Result<PooledConnection> {
+        let deadline = self
+            .inner
+            .config
+            .checkout_timeout
+            .map(|x| std::time::Instant::now() + x);
+        let mut waited = false;
+        let mut state = self.lock();
+
+        loop {
+            while let Some(idle) = state.idle.pop_back() {
+                if self.is_reusable(&idle, state.total) {
+                    return Ok(PooledConnection {
+                        pool: self.clone(),
+                        conn: Some(idle.conn),
+                        created: idle.created,
+                    });
+                }
+
+                state.total -= 1;
+                state.stats.discarded += 1;
+            }
+
+            if state.total < self.inner.config.max_size {
+                state.total += 1;
+                drop(state);
+
+                let conn = self.connect()?;
+
+                return Ok(PooledConnection {
+                    pool: self.clone(),
+                    conn: Some(conn),
+                    created: std::time::Instant::now(),
+                });
+            }
+
+            if !waited {
+                waited = true;
+                state.stats.waits += 1;
+            }
+
+            state = match deadline {
+                Some(deadline) => {
+                    let timeout = deadline.saturating_duration_since(std::time::Instant::now());
+
+                    if timeout.is_zero() {
+                        return Err(crate::errors::Error::PoolTimeout);
+                    }
+
+                    self.inner
+                        .available
+                        .wait_timeout(state, timeout)
+                        .unwrap_or_else(std::sync::PoisonError::into_inner)
+                        .0
+                }
+                None => self
+                    .inner
+                    .available
+                    .wait(state)
+                    .unwrap_or_else(std::sync::PoisonError::into_inner),
+            };
+        }
+    }
+
+    /**
+     * Returns the current statistics of the pool.
+     */
+    pub fn stats(&self) -> Stats {
+        let state = self.lock();
+
+        Stats {
+            in_use: state.total - state.idle.len(),
+            idle: state.idle.len(),
+            ..state.stats
+        }
+    }
+
+    /**
+     * Opens a new connection, its slot must be already counted in `State::total`.
+     */
+    fn connect(&self) -> crate::errors::Result<crate::Connection> {
+        let config = &self.inner.config;
+
+        let conn = crate::Connection::new(&config.dsn).and_then(|conn| {
+            if let Some(after_connect) = &config.after_connect {
+                after_connect(&conn)?;
+            }
+
+            Ok(conn)
+        });
+
+        if conn.is_err() {
+            let mut state = self.lock();
+            state.total -= 1;
+            state.stats.creation_failures += 1;
+            self.inner.available.notify_one();
+        }
+
+        conn
+    }
+
+    fn checkin(&self, conn: crate::Connection, created: std::time::Instant) {
+        let mut state = self.lock();
+
+        state.idle.push_back(Idle {
+            conn,
+            created,
+            since: std::time::Instant::now(),
+        });
+        self.inner.available.notify_one();
+    }
+
+    fn discard(&self) {
+        let mut state = self.lock();
+
+        state.total -= 1;
+        state.stats.discarded += 1;
+        self.inner.available.notify_one();
+    }
+
+    fn is_healthy(conn: &crate::Connection) -> bool {
+        conn.status() == crate::connection::Status::Ok
+            && !matches!(
+                conn.transaction_status(),
+                crate::transaction::Status::InError
+                    | crate::transaction::Status::InTrans
+                    | crate::transaction::Status::Active
+                    | crate::transaction::Status::Unknow
+            )
+    }
+
+    fn is_expired(&self, created: std::time::Instant) -> bool {
+        self.inner
+            .config
+            .max_lifetime
+            .is_some_and(|x| created.elapsed() >= x)
+    }
+
+    fn is_reusable(&self, idle: &Idle, total: usize) -> bool {
+        let config = &self.inner.config;
+        let idle_expired = total > config.min_size
+            && config
+                .idle_timeout
+                .is_some_and(|x| idle.since.elapsed() >= x);
+
+        !idle_expired && !self.is_expired(idle.created) && Self::is_healthy(&idle.conn)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.inner
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/**
+ * A connection checked out from a `Pool`, returned to the pool on drop.
+ *
+ * The connection is closed instead if a transaction is still open, failed or active, if its
+ * reset fails or if it exceeded its lifetime.
+ */
+#[derive(Debug)]
+pub struct PooledConnection {
+    pool: Pool,
+    conn: Option<crate::Connection>,
+    created: std::time::Instant,
+}
+
+impl PooledConnection {
+    /**
+     * Removes the connection from the pool.
+     */
+    pub fn detach(mut self) -> crate::Connection {
+        self.pool.discard();
+        self.conn.take().unwrap()
+    }
+}
+
+impl std::ops::Deref for PooledConnection {
+    type Target = crate::Connection;
+
+    fn deref(&self) -> &Self::Target {
+        self.conn.as_ref().unwrap()
+    }
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        let Some(conn) = self.conn.take() else {
+            return;
+        };
+
+        if !Pool::is_healthy(&conn) || self.pool.is_expired(self.created) {
+            self.pool.discard();
+            return;
+        }
+
+        if let Some(reset) = &self.pool.inner.config.reset {
+            if let Err(err) = conn.try_exec(reset) {
+                log::warn!("Unable to reset pooled connection: {err}");
+                self.pool.discard();
+                return;
+            }
+        }
+
+        self.pool.checkin(conn, self.created);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    fn config() -> super::Config {
+        super::Config::new(&crate::test::dsn())
+    }
+
+    #[test]
+    fn get() {
+        let pool = super::Pool::new(config().min_size(1).max_size(2)).unwrap();
+        assert_eq!(pool.stats().idle, 1);
+
+        let first = pool.get().unwrap();
+        let second = pool.get().unwrap();
+        assert_eq!(pool.stats().in_use, 2);
+        assert_eq!(pool.stats().idle, 0);
+
+        let pid = second.backend_pid();
+        drop(first);
+        drop(second);
+        assert_eq!(pool.stats().idle, 2);
+
+        let conn = pool.get().unwrap();
+        assert_eq!(conn.backend_pid(), pid);
+        assert_eq!(pool.stats().idle, 1);
+    }
+
+    #[test]
+    fn timeout() {
+        let pool = super::Pool::new(
+            config()
+                .max_size(1)
+                .checkout_timeout(Some(std::time::Duration::from_millis(10))),
+        )
+        .unwrap();
+
+        let _conn = pool.get().unwrap();
+        assert_eq!(pool.get().err(), Some(crate::errors::Error::PoolTimeout));
+        assert_eq!(pool.stats().waits, 1);
+    }
+
+    #[test]
+    fn wait() {
+        let pool = super::Pool::new(config().max_size(1)).unwrap();
+
+        let conn = pool.get().unwrap();
+        let pid = conn.backend_pid();
+
+        let other = pool.clone();
+        let handle = std::thread::spawn(move || other.get().map(|x| x.backend_pid()));
+
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        drop(conn);
+
+        assert_eq!(handle.join().unwrap(), Ok(pid));
+        assert_eq!(pool.stats().waits, 1);
+    }
+
+    #[test]
+    fn discard_in_error() {
+        let pool = super::Pool::new(config()).unwrap();
+
+        let conn = pool.get().unwrap();
+        conn.try_exec("BEGIN").unwrap();
+        assert!(conn.try_exec("SELECT 1/0").is_err());
+        drop(conn);
+
+        let stats = pool.stats();
+        assert_eq!(stats.idle, 0);
+        assert_eq!(stats.in_use, 0);
+        assert_eq!(stats.discarded, 1);
+    }
+
+    #[test]
+    fn discard_in_trans() {
+        let pool = super::Pool::new(config().reset(None)).unwrap();
+
+        let conn = pool.get().unwrap();
+        conn.try_exec("BEGIN").unwrap();
+        drop(conn);
+
+        let stats = pool.stats();
+        assert_eq!(stats.idle, 0);
+        assert_eq!(stats.discarded, 1);
+    }
+
+    #[test]
+    fn reset() {
+        let pool = super::Pool::new(config().max_size(1)).unwrap();
+
+        let conn = pool.get().unwrap();
+        conn.try_exec("SET application_name = 'reset'").unwrap();
+        drop(conn);
+
+        let conn = pool.get().unwrap();
+        let result = conn.try_exec("SHOW application_name").unwrap();
+        assert_eq!(result.get::<&str>(0, 0), Ok(""));
+    }
+
+    #[test]
+    fn after_connect() {
+        let pool = super::Pool::new(config().after_connect(|conn| {
+            conn.try_exec("SET application_name = 'after_connect'")
+                .map(|_| ())
+        }))
+        .unwrap();
+
+        let conn = pool.get().unwrap();
+        let result = conn.try_exec("SHOW application_name").unwrap();
+        assert_eq!(result.get::<&str>(0, 0), Ok("after_connect"));
+    }
+
+    #[test]
+    fn creation_failure() {
+        let pool = super::Pool::new(super::Config::new("host=localhost port=1")).unwrap();
+
+        assert!(pool.get().is_err());
+        assert_eq!(pool.stats().creation_failures, 1);
+        assert_eq!(pool.stats().in_use, 0);
+    }
+
+    #[test]
+    fn max_lifetime() {
+        let pool =
+            super::Pool::new(config().max_lifetime(Some(std::time::Duration::ZERO))).unwrap();
+
+        drop(pool.get().unwrap());
+        assert_eq!(pool.stats().idle, 0);
+        assert_eq!(pool.stats().discarded, 1);
+    }
+}
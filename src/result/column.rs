@@ -0,0 +1,46 @@
+/**
+ * Metadata of a column of a [`crate::PQResult`].
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Column {
+    /** The column name. */
+    pub name: String,
+    /** The column number. */
+    pub number: usize,
+    /** The OID of the column data type. */
+    pub type_oid: crate::Oid,
+    /** The OID of the table from which the column was fetched. */
+    pub table: Option<crate::Oid>,
+    /** The column number within its table. */
+    pub tablecol: usize,
+    /** The type modifier of the column. */
+    pub modifier: Option<i32>,
+    /** The size in bytes of the column, `None` for variable-length data types. */
+    pub size: Option<usize>,
+    /** The format of the column data. */
+    pub format: crate::Format,
+}
+
+impl Column {
+    pub(crate) fn new(result: &crate::PQResult, number: usize) -> crate::errors::Result<Self> {
+        let column = Self {
+            name: result.field_name(number)?.unwrap_or_default(),
+            number,
+            type_oid: result.field_type(number),
+            table: result.field_table(number),
+            tablecol: result.field_tablecol(number),
+            modifier: result.field_mod(number),
+            size: result.field_size(number),
+            format: result.field_format(number),
+        };
+
+        Ok(column)
+    }
+
+    /**
+     * Returns the column data type, `libpq::types::UNKNOWN` if the type isn't a builtin one.
+     */
+    pub fn ty(&self) -> crate::Type {
+        crate::Type::try_from(self.type_oid).unwrap_or(crate::types::UNKNOWN)
+    }
+}
@@ -1,14 +1,18 @@
 mod attribute;
+mod column;
 mod error_field;
+mod row;
 
 pub use attribute::*;
+pub use column::*;
 pub use error_field::*;
+pub use row::*;
 
 use std::os::raw;
 
-#[derive(Clone)]
 pub struct PQResult {
     result: *mut pq_sys::PGresult,
+    column_numbers: std::sync::OnceLock<std::collections::HashMap<String, usize>>,
 }
 
 impl PQResult {
@@ -111,6 +115,56 @@ impl PQResult {
         }
     }
 
+    /**
+     * Returns the column number associated with the given column name.
+     *
+     * Unlike `libpq::PQResult::field_number`, the name is matched exactly and the lookup table
+     * is computed only once per result.
+     */
+    pub fn column_number(&self, name: &str) -> Option<usize> {
+        self.column_numbers
+            .get_or_init(|| {
+                let mut numbers = std::collections::HashMap::new();
+
+                for number in (0..self.nfields()).rev() {
+                    if let Ok(Some(name)) = self.field_name(number) {
+                        numbers.insert(name, number);
+                    }
+                }
+
+                numbers
+            })
+            .get(name)
+            .copied()
+    }
+
+    /**
+     * Returns the metadata of all columns.
+     */
+    pub fn columns(&self) -> crate::errors::Result<Vec<crate::result::Column>> {
+        (0..self.nfields())
+            .map(|x| crate::result::Column::new(self, x))
+            .collect()
+    }
+
+    /**
+     * Returns an iterator over the rows of the result.
+     */
+    pub fn rows(&self) -> crate::result::Rows<'_> {
+        crate::result::Rows::new(self)
+    }
+
+    /**
+     * Returns the given row, or `None` if it is out of range.
+     */
+    pub fn row(&self, number: usize) -> Option<crate::result::Row<'_>> {
+        if number < self.ntuples() {
+            Some(crate::result::Row::new(self, number))
+        } else {
+            None
+        }
+    }
+
     /**
      * Returns the OID of the table from which the given column was fetched.
      *
@@ -216,6 +270,26 @@ impl PQResult {
         }
     }
 
+    /**
+     * Returns a single field value of one row of a `Result`, converted to `T`.
+     *
+     * Types unknown to this crate are reported as `libpq::types::UNKNOWN`, an out of range
+     * `column` is a `ColumnNotFound` error.
+     */
+    pub fn get<'a, T: crate::types::FromSql<'a>>(
+        &'a self,
+        row: usize,
+        column: usize,
+    ) -> crate::errors::Result<T> {
+        if column >= self.nfields() {
+            return Err(crate::errors::Error::ColumnNotFound(column.to_string()));
+        }
+
+        let ty = crate::Type::try_from(self.field_type(column)).unwrap_or(crate::types::UNKNOWN);
+
+        T::from_sql(&ty, self.field_format(column), self.value(row, column))
+    }
+
     /**
      * Tests a field for a null value.
      *
@@ -370,6 +444,7 @@ impl PQResult {
         let success = unsafe {
             pq_sys::PQsetResultAttrs(self.into(), attributes.len() as i32, attr.as_mut_ptr())
         };
+        self.column_numbers.take();
 
         if success == 0 {
             Err(crate::errors::Error::Unknow)
@@ -475,6 +550,15 @@ impl PQResult {
     }
 }
 
+impl<'res> IntoIterator for &'res PQResult {
+    type Item = crate::result::Row<'res>;
+    type IntoIter = crate::result::Rows<'res>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows()
+    }
+}
+
 unsafe impl Send for PQResult {}
 
 unsafe impl Sync for PQResult {}
@@ -488,7 +572,10 @@ impl Drop for PQResult {
 #[doc(hidden)]
 impl From<*mut pq_sys::PGresult> for PQResult {
     fn from(result: *mut pq_sys::PGresult) -> Self {
-        PQResult { result }
+        PQResult {
+            result,
+            column_numbers: std::sync::OnceLock::new(),
+        }
     }
 }
 
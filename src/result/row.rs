@@ -0,0 +1,178 @@
+/**
+ * A trait implemented by types that can index into the columns of a row.
+ */
+pub trait ColumnIndex: std::fmt::Display {
+    /**
+     * Returns the column number, or `None` if there is no such column.
+     */
+    fn index(&self, result: &crate::PQResult) -> Option<usize>;
+}
+
+impl ColumnIndex for usize {
+    fn index(&self, result: &crate::PQResult) -> Option<usize> {
+        if *self < result.nfields() {
+            Some(*self)
+        } else {
+            None
+        }
+    }
+}
+
+/**
+ * Unlike `libpq::PQResult::field_number`, names are matched exactly, without case folding.
+ */
+impl ColumnIndex for str {
+    fn index(&self, result: &crate::PQResult) -> Option<usize> {
+        result.column_number(self)
+    }
+}
+
+impl ColumnIndex for String {
+    fn index(&self, result: &crate::PQResult) -> Option<usize> {
+        self.as_str().index(result)
+    }
+}
+
+impl<T: ColumnIndex + ?Sized> ColumnIndex for &T {
+    fn index(&self, result: &crate::PQResult) -> Option<usize> {
+        (*self).index(result)
+    }
+}
+
+/**
+ * A row of a [`crate::PQResult`].
+ */
+#[derive(Clone, Copy)]
+pub struct Row<'res> {
+    result: &'res crate::PQResult,
+    number: usize,
+}
+
+impl<'res> Row<'res> {
+    pub(crate) fn new(result: &'res crate::PQResult, number: usize) -> Self {
+        Self { result, number }
+    }
+
+    /**
+     * Returns the row number.
+     */
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    /**
+     * Returns the number of columns.
+     */
+    pub fn len(&self) -> usize {
+        self.result.nfields()
+    }
+
+    /**
+     * Returns `true` if the row has no columns.
+     */
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /**
+     * Returns the result this row belongs to.
+     */
+    pub fn result(&self) -> &'res crate::PQResult {
+        self.result
+    }
+
+    /**
+     * Returns the raw value of a column, `None` for a null value.
+     */
+    pub fn value<I: ColumnIndex>(&self, index: I) -> crate::errors::Result<Option<&'res [u8]>> {
+        let column = self.column_index(&index)?;
+
+        Ok(self.result.value(self.number, column))
+    }
+
+    /**
+     * Returns the value of a column, converted to `T`.
+     */
+    pub fn get<T: crate::types::FromSql<'res>, I: ColumnIndex>(
+        &self,
+        index: I,
+    ) -> crate::errors::Result<T> {
+        let column = self.column_index(&index)?;
+
+        self.result.get(self.number, column)
+    }
+
+    /**
+     * Tests a column for a null value.
+     */
+    pub fn is_null<I: ColumnIndex>(&self, index: I) -> crate::errors::Result<bool> {
+        let column = self.column_index(&index)?;
+
+        Ok(self.result.is_null(self.number, column))
+    }
+
+    fn column_index<I: ColumnIndex>(&self, index: &I) -> crate::errors::Result<usize> {
+        index
+            .index(self.result)
+            .ok_or_else(|| crate::errors::Error::ColumnNotFound(index.to_string()))
+    }
+}
+
+impl std::fmt::Debug for Row<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut list = f.debug_list();
+
+        for column in 0..self.len() {
+            list.entry(
+                &self
+                    .result
+                    .value(self.number, column)
+                    .map(String::from_utf8_lossy),
+            );
+        }
+
+        list.finish()
+    }
+}
+
+/**
+ * An iterator over the rows of a [`crate::PQResult`].
+ *
+ * See `libpq::PQResult::rows`.
+ */
+#[derive(Clone, Debug)]
+pub struct Rows<'res> {
+    result: &'res crate::PQResult,
+    range: std::ops::Range<usize>,
+}
+
+impl<'res> Rows<'res> {
+    pub(crate) fn new(result: &'res crate::PQResult) -> Self {
+        Self {
+            result,
+            range: 0..result.ntuples(),
+        }
+    }
+}
+
+impl<'res> Iterator for Rows<'res> {
+    type Item = Row<'res>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|x| Row::new(self.result, x))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Rows<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|x| Row::new(self.result, x))
+    }
+}
+
+impl ExactSizeIterator for Rows<'_> {}
+
+impl std::iter::FusedIterator for Rows<'_> {}
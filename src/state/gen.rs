@@ -1814,269 +1814,1536 @@ pub const INDEX_CORRUPTED: State = State {
 };
 
 impl State {
-    /// Creates a `State` from its error code.
-    pub fn from_code(s: &str) -> State {
+    /// Creates a `State` from its error code, returns `None` for an unknown code.
+    pub fn try_from_code(s: &str) -> Option<State> {
         match s {
-            "00000" => SUCCESSFUL_COMPLETION,
-            "01000" => WARNING,
-            "01003" => WARNING_NULL_VALUE_ELIMINATED_IN_SET_FUNCTION,
-            "01004" => WARNING_STRING_DATA_RIGHT_TRUNCATION,
-            "01006" => WARNING_PRIVILEGE_NOT_REVOKED,
-            "01007" => WARNING_PRIVILEGE_NOT_GRANTED,
-            "01008" => WARNING_IMPLICIT_ZERO_BIT_PADDING,
-            "0100C" => WARNING_DYNAMIC_RESULT_SETS_RETURNED,
-            "01P01" => WARNING_DEPRECATED_FEATURE,
-            "02000" => NO_DATA,
-            "02001" => NO_ADDITIONAL_DYNAMIC_RESULT_SETS_RETURNED,
-            "03000" => SQL_STATEMENT_NOT_YET_COMPLETE,
-            "08000" => CONNECTION_EXCEPTION,
-            "08001" => SQLCLIENT_UNABLE_TO_ESTABLISH_SQLCONNECTION,
-            "08003" => CONNECTION_DOES_NOT_EXIST,
-            "08004" => SQLSERVER_REJECTED_ESTABLISHMENT_OF_SQLCONNECTION,
-            "08006" => CONNECTION_FAILURE,
-            "08007" => TRANSACTION_RESOLUTION_UNKNOWN,
-            "08P01" => PROTOCOL_VIOLATION,
-            "09000" => TRIGGERED_ACTION_EXCEPTION,
-            "0A000" => FEATURE_NOT_SUPPORTED,
-            "0B000" => INVALID_TRANSACTION_INITIATION,
-            "0F000" => LOCATOR_EXCEPTION,
-            "0F001" => L_E_INVALID_SPECIFICATION,
-            "0L000" => INVALID_GRANTOR,
-            "0LP01" => INVALID_GRANT_OPERATION,
-            "0P000" => INVALID_ROLE_SPECIFICATION,
-            "0Z000" => DIAGNOSTICS_EXCEPTION,
-            "0Z002" => STACKED_DIAGNOSTICS_ACCESSED_WITHOUT_ACTIVE_HANDLER,
-            "20000" => CASE_NOT_FOUND,
-            "21000" => CARDINALITY_VIOLATION,
-            "22000" => DATA_EXCEPTION,
-            "22001" => STRING_DATA_RIGHT_TRUNCATION,
-            "22002" => NULL_VALUE_NO_INDICATOR_PARAMETER,
-            "22003" => NUMERIC_VALUE_OUT_OF_RANGE,
-            "22004" => NULL_VALUE_NOT_ALLOWED,
-            "22005" => ERROR_IN_ASSIGNMENT,
-            "22007" => INVALID_DATETIME_FORMAT,
-            "22008" => DATETIME_VALUE_OUT_OF_RANGE,
-            "22009" => INVALID_TIME_ZONE_DISPLACEMENT_VALUE,
-            "2200B" => ESCAPE_CHARACTER_CONFLICT,
-            "2200C" => INVALID_USE_OF_ESCAPE_CHARACTER,
-            "2200D" => INVALID_ESCAPE_OCTET,
-            "2200F" => ZERO_LENGTH_CHARACTER_STRING,
-            "2200G" => MOST_SPECIFIC_TYPE_MISMATCH,
-            "2200H" => SEQUENCE_GENERATOR_LIMIT_EXCEEDED,
-            "2200L" => NOT_AN_XML_DOCUMENT,
-            "2200M" => INVALID_XML_DOCUMENT,
-            "2200N" => INVALID_XML_CONTENT,
-            "2200S" => INVALID_XML_COMMENT,
-            "2200T" => INVALID_XML_PROCESSING_INSTRUCTION,
-            "22010" => INVALID_INDICATOR_PARAMETER_VALUE,
-            "22011" => SUBSTRING_ERROR,
-            "22012" => DIVISION_BY_ZERO,
-            "22013" => INVALID_PRECEDING_OR_FOLLOWING_SIZE,
-            "22014" => INVALID_ARGUMENT_FOR_NTILE,
-            "22015" => INTERVAL_FIELD_OVERFLOW,
-            "22016" => INVALID_ARGUMENT_FOR_NTH_VALUE,
-            "22018" => INVALID_CHARACTER_VALUE_FOR_CAST,
-            "22019" => INVALID_ESCAPE_CHARACTER,
-            "2201B" => INVALID_REGULAR_EXPRESSION,
-            "2201E" => INVALID_ARGUMENT_FOR_LOG,
-            "2201F" => INVALID_ARGUMENT_FOR_POWER_FUNCTION,
-            "2201G" => INVALID_ARGUMENT_FOR_WIDTH_BUCKET_FUNCTION,
-            "2201W" => INVALID_ROW_COUNT_IN_LIMIT_CLAUSE,
-            "2201X" => INVALID_ROW_COUNT_IN_RESULT_OFFSET_CLAUSE,
-            "22021" => CHARACTER_NOT_IN_REPERTOIRE,
-            "22022" => INDICATOR_OVERFLOW,
-            "22023" => INVALID_PARAMETER_VALUE,
-            "22024" => UNTERMINATED_C_STRING,
-            "22025" => INVALID_ESCAPE_SEQUENCE,
-            "22026" => STRING_DATA_LENGTH_MISMATCH,
-            "22027" => TRIM_ERROR,
-            "2202E" => ARRAY_SUBSCRIPT_ERROR,
-            "2202G" => INVALID_TABLESAMPLE_REPEAT,
-            "2202H" => INVALID_TABLESAMPLE_ARGUMENT,
-            "22030" => DUPLICATE_JSON_OBJECT_KEY_VALUE,
-            "22031" => INVALID_ARGUMENT_FOR_SQL_JSON_DATETIME_FUNCTION,
-            "22032" => INVALID_JSON_TEXT,
-            "22033" => INVALID_SQL_JSON_SUBSCRIPT,
-            "22034" => MORE_THAN_ONE_SQL_JSON_ITEM,
-            "22035" => NO_SQL_JSON_ITEM,
-            "22036" => NON_NUMERIC_SQL_JSON_ITEM,
-            "22037" => NON_UNIQUE_KEYS_IN_A_JSON_OBJECT,
-            "22038" => SINGLETON_SQL_JSON_ITEM_REQUIRED,
-            "22039" => SQL_JSON_ARRAY_NOT_FOUND,
-            "2203A" => SQL_JSON_MEMBER_NOT_FOUND,
-            "2203B" => SQL_JSON_NUMBER_NOT_FOUND,
-            "2203C" => SQL_JSON_OBJECT_NOT_FOUND,
-            "2203D" => TOO_MANY_JSON_ARRAY_ELEMENTS,
-            "2203E" => TOO_MANY_JSON_OBJECT_MEMBERS,
-            "2203F" => SQL_JSON_SCALAR_REQUIRED,
-            "22P01" => FLOATING_POINT_EXCEPTION,
-            "22P02" => INVALID_TEXT_REPRESENTATION,
-            "22P03" => INVALID_BINARY_REPRESENTATION,
-            "22P04" => BAD_COPY_FILE_FORMAT,
-            "22P05" => UNTRANSLATABLE_CHARACTER,
-            "22P06" => NONSTANDARD_USE_OF_ESCAPE_CHARACTER,
-            "23000" => INTEGRITY_CONSTRAINT_VIOLATION,
-            "23001" => RESTRICT_VIOLATION,
-            "23502" => NOT_NULL_VIOLATION,
-            "23503" => FOREIGN_KEY_VIOLATION,
-            "23505" => UNIQUE_VIOLATION,
-            "23514" => CHECK_VIOLATION,
-            "23P01" => EXCLUSION_VIOLATION,
-            "24000" => INVALID_CURSOR_STATE,
-            "25000" => INVALID_TRANSACTION_STATE,
-            "25001" => ACTIVE_SQL_TRANSACTION,
-            "25002" => BRANCH_TRANSACTION_ALREADY_ACTIVE,
-            "25003" => INAPPROPRIATE_ACCESS_MODE_FOR_BRANCH_TRANSACTION,
-            "25004" => INAPPROPRIATE_ISOLATION_LEVEL_FOR_BRANCH_TRANSACTION,
-            "25005" => NO_ACTIVE_SQL_TRANSACTION_FOR_BRANCH_TRANSACTION,
-            "25006" => READ_ONLY_SQL_TRANSACTION,
-            "25007" => SCHEMA_AND_DATA_STATEMENT_MIXING_NOT_SUPPORTED,
-            "25008" => HELD_CURSOR_REQUIRES_SAME_ISOLATION_LEVEL,
-            "25P01" => NO_ACTIVE_SQL_TRANSACTION,
-            "25P02" => IN_FAILED_SQL_TRANSACTION,
-            "25P03" => IDLE_IN_TRANSACTION_SESSION_TIMEOUT,
-            "26000" => UNDEFINED_PSTATEMENT,
-            "27000" => TRIGGERED_DATA_CHANGE_VIOLATION,
-            "28000" => INVALID_AUTHORIZATION_SPECIFICATION,
-            "28P01" => INVALID_PASSWORD,
-            "2B000" => DEPENDENT_PRIVILEGE_DESCRIPTORS_STILL_EXIST,
-            "2BP01" => DEPENDENT_OBJECTS_STILL_EXIST,
-            "2D000" => INVALID_TRANSACTION_TERMINATION,
-            "2F000" => SQL_ROUTINE_EXCEPTION,
-            "2F002" => S_R_E_MODIFYING_SQL_DATA_NOT_PERMITTED,
-            "2F003" => S_R_E_PROHIBITED_SQL_STATEMENT_ATTEMPTED,
-            "2F004" => S_R_E_READING_SQL_DATA_NOT_PERMITTED,
-            "2F005" => S_R_E_FUNCTION_EXECUTED_NO_RETURN_STATEMENT,
-            "34000" => UNDEFINED_CURSOR,
-            "38000" => EXTERNAL_ROUTINE_EXCEPTION,
-            "38001" => E_R_E_CONTAINING_SQL_NOT_PERMITTED,
-            "38002" => E_R_E_MODIFYING_SQL_DATA_NOT_PERMITTED,
-            "38003" => E_R_E_PROHIBITED_SQL_STATEMENT_ATTEMPTED,
-            "38004" => E_R_E_READING_SQL_DATA_NOT_PERMITTED,
-            "39000" => EXTERNAL_ROUTINE_INVOCATION_EXCEPTION,
-            "39001" => E_R_I_E_INVALID_SQLSTATE_RETURNED,
-            "39004" => E_R_I_E_NULL_VALUE_NOT_ALLOWED,
-            "39P01" => E_R_I_E_TRIGGER_PROTOCOL_VIOLATED,
-            "39P02" => E_R_I_E_SRF_PROTOCOL_VIOLATED,
-            "39P03" => E_R_I_E_EVENT_TRIGGER_PROTOCOL_VIOLATED,
-            "3B000" => SAVEPOINT_EXCEPTION,
-            "3B001" => S_E_INVALID_SPECIFICATION,
-            "3D000" => UNDEFINED_DATABASE,
-            "3F000" => UNDEFINED_SCHEMA,
-            "40000" => TRANSACTION_ROLLBACK,
-            "40001" => T_R_SERIALIZATION_FAILURE,
-            "40002" => T_R_INTEGRITY_CONSTRAINT_VIOLATION,
-            "40003" => T_R_STATEMENT_COMPLETION_UNKNOWN,
-            "40P01" => T_R_DEADLOCK_DETECTED,
-            "42000" => SYNTAX_ERROR_OR_ACCESS_RULE_VIOLATION,
-            "42501" => INSUFFICIENT_PRIVILEGE,
-            "42601" => SYNTAX_ERROR,
-            "42602" => INVALID_NAME,
-            "42611" => INVALID_COLUMN_DEFINITION,
-            "42622" => NAME_TOO_LONG,
-            "42701" => DUPLICATE_COLUMN,
-            "42702" => AMBIGUOUS_COLUMN,
-            "42703" => UNDEFINED_COLUMN,
-            "42704" => UNDEFINED_OBJECT,
-            "42710" => DUPLICATE_OBJECT,
-            "42712" => DUPLICATE_ALIAS,
-            "42723" => DUPLICATE_FUNCTION,
-            "42725" => AMBIGUOUS_FUNCTION,
-            "42803" => GROUPING_ERROR,
-            "42804" => DATATYPE_MISMATCH,
-            "42809" => WRONG_OBJECT_TYPE,
-            "42830" => INVALID_FOREIGN_KEY,
-            "42846" => CANNOT_COERCE,
-            "42883" => UNDEFINED_FUNCTION,
-            "428C9" => GENERATED_ALWAYS,
-            "42939" => RESERVED_NAME,
-            "42P01" => UNDEFINED_TABLE,
-            "42P02" => UNDEFINED_PARAMETER,
-            "42P03" => DUPLICATE_CURSOR,
-            "42P04" => DUPLICATE_DATABASE,
-            "42P05" => DUPLICATE_PSTATEMENT,
-            "42P06" => DUPLICATE_SCHEMA,
-            "42P07" => DUPLICATE_TABLE,
-            "42P08" => AMBIGUOUS_PARAMETER,
-            "42P09" => AMBIGUOUS_ALIAS,
-            "42P10" => INVALID_COLUMN_REFERENCE,
-            "42P11" => INVALID_CURSOR_DEFINITION,
-            "42P12" => INVALID_DATABASE_DEFINITION,
-            "42P13" => INVALID_FUNCTION_DEFINITION,
-            "42P14" => INVALID_PSTATEMENT_DEFINITION,
-            "42P15" => INVALID_SCHEMA_DEFINITION,
-            "42P16" => INVALID_TABLE_DEFINITION,
-            "42P17" => INVALID_OBJECT_DEFINITION,
-            "42P18" => INDETERMINATE_DATATYPE,
-            "42P19" => INVALID_RECURSION,
-            "42P20" => WINDOWING_ERROR,
-            "42P21" => COLLATION_MISMATCH,
-            "42P22" => INDETERMINATE_COLLATION,
-            "44000" => WITH_CHECK_OPTION_VIOLATION,
-            "53000" => INSUFFICIENT_RESOURCES,
-            "53100" => DISK_FULL,
-            "53200" => OUT_OF_MEMORY,
-            "53300" => TOO_MANY_CONNECTIONS,
-            "53400" => CONFIGURATION_LIMIT_EXCEEDED,
-            "54000" => PROGRAM_LIMIT_EXCEEDED,
-            "54001" => STATEMENT_TOO_COMPLEX,
-            "54011" => TOO_MANY_COLUMNS,
-            "54023" => TOO_MANY_ARGUMENTS,
-            "55000" => OBJECT_NOT_IN_PREREQUISITE_STATE,
-            "55006" => OBJECT_IN_USE,
-            "55P02" => CANT_CHANGE_RUNTIME_PARAM,
-            "55P03" => LOCK_NOT_AVAILABLE,
-            "55P04" => UNSAFE_NEW_ENUM_VALUE_USAGE,
-            "57000" => OPERATOR_INTERVENTION,
-            "57014" => QUERY_CANCELED,
-            "57P01" => ADMIN_SHUTDOWN,
-            "57P02" => CRASH_SHUTDOWN,
-            "57P03" => CANNOT_CONNECT_NOW,
-            "57P04" => DATABASE_DROPPED,
-            "57P05" => IDLE_SESSION_TIMEOUT,
-            "58000" => SYSTEM_ERROR,
-            "58030" => IO_ERROR,
-            "58P01" => UNDEFINED_FILE,
-            "58P02" => DUPLICATE_FILE,
-            "72000" => SNAPSHOT_TOO_OLD,
-            "F0000" => CONFIG_FILE_ERROR,
-            "F0001" => LOCK_FILE_EXISTS,
-            "HV000" => FDW_ERROR,
-            "HV001" => FDW_OUT_OF_MEMORY,
-            "HV002" => FDW_DYNAMIC_PARAMETER_VALUE_NEEDED,
-            "HV004" => FDW_INVALID_DATA_TYPE,
-            "HV005" => FDW_COLUMN_NAME_NOT_FOUND,
-            "HV006" => FDW_INVALID_DATA_TYPE_DESCRIPTORS,
-            "HV007" => FDW_INVALID_COLUMN_NAME,
-            "HV008" => FDW_INVALID_COLUMN_NUMBER,
-            "HV009" => FDW_INVALID_USE_OF_NULL_POINTER,
-            "HV00A" => FDW_INVALID_STRING_FORMAT,
-            "HV00B" => FDW_INVALID_HANDLE,
-            "HV00C" => FDW_INVALID_OPTION_INDEX,
-            "HV00D" => FDW_INVALID_OPTION_NAME,
-            "HV00J" => FDW_OPTION_NAME_NOT_FOUND,
-            "HV00K" => FDW_REPLY_HANDLE,
-            "HV00L" => FDW_UNABLE_TO_CREATE_EXECUTION,
-            "HV00M" => FDW_UNABLE_TO_CREATE_REPLY,
-            "HV00N" => FDW_UNABLE_TO_ESTABLISH_CONNECTION,
-            "HV00P" => FDW_NO_SCHEMAS,
-            "HV00Q" => FDW_SCHEMA_NOT_FOUND,
-            "HV00R" => FDW_TABLE_NOT_FOUND,
-            "HV010" => FDW_FUNCTION_SEQUENCE_ERROR,
-            "HV014" => FDW_TOO_MANY_HANDLES,
-            "HV021" => FDW_INCONSISTENT_DESCRIPTOR_INFORMATION,
-            "HV024" => FDW_INVALID_ATTRIBUTE_VALUE,
-            "HV090" => FDW_INVALID_STRING_LENGTH_OR_BUFFER_LENGTH,
-            "HV091" => FDW_INVALID_DESCRIPTOR_FIELD_IDENTIFIER,
-            "P0000" => PLPGSQL_ERROR,
-            "P0001" => RAISE_EXCEPTION,
-            "P0002" => NO_DATA_FOUND,
-            "P0003" => TOO_MANY_ROWS,
-            "P0004" => ASSERT_FAILURE,
-            "XX000" => INTERNAL_ERROR,
-            "XX001" => DATA_CORRUPTED,
-            "XX002" => INDEX_CORRUPTED,
-            _ => unreachable!(),
+            "00000" => Some(SUCCESSFUL_COMPLETION),
+            "01000" => Some(WARNING),
+            "01003" => Some(WARNING_NULL_VALUE_ELIMINATED_IN_SET_FUNCTION),
+            "01004" => Some(WARNING_STRING_DATA_RIGHT_TRUNCATION),
+            "01006" => Some(WARNING_PRIVILEGE_NOT_REVOKED),
+            "01007" => Some(WARNING_PRIVILEGE_NOT_GRANTED),
+            "01008" => Some(WARNING_IMPLICIT_ZERO_BIT_PADDING),
+            "0100C" => Some(WARNING_DYNAMIC_RESULT_SETS_RETURNED),
+            "01P01" => Some(WARNING_DEPRECATED_FEATURE),
+            "02000" => Some(NO_DATA),
+            "02001" => Some(NO_ADDITIONAL_DYNAMIC_RESULT_SETS_RETURNED),
+            "03000" => Some(SQL_STATEMENT_NOT_YET_COMPLETE),
+            "08000" => Some(CONNECTION_EXCEPTION),
+            "08001" => Some(SQLCLIENT_UNABLE_TO_ESTABLISH_SQLCONNECTION),
+            "08003" => Some(CONNECTION_DOES_NOT_EXIST),
+            "08004" => Some(SQLSERVER_REJECTED_ESTABLISHMENT_OF_SQLCONNECTION),
+            "08006" => Some(CONNECTION_FAILURE),
+            "08007" => Some(TRANSACTION_RESOLUTION_UNKNOWN),
+            "08P01" => Some(PROTOCOL_VIOLATION),
+            "09000" => Some(TRIGGERED_ACTION_EXCEPTION),
+            "0A000" => Some(FEATURE_NOT_SUPPORTED),
+            "0B000" => Some(INVALID_TRANSACTION_INITIATION),
+            "0F000" => Some(LOCATOR_EXCEPTION),
+            "0F001" => Some(L_E_INVALID_SPECIFICATION),
+            "0L000" => Some(INVALID_GRANTOR),
+            "0LP01" => Some(INVALID_GRANT_OPERATION),
+            "0P000" => Some(INVALID_ROLE_SPECIFICATION),
+            "0Z000" => Some(DIAGNOSTICS_EXCEPTION),
+            "0Z002" => Some(STACKED_DIAGNOSTICS_ACCESSED_WITHOUT_ACTIVE_HANDLER),
+            "20000" => Some(CASE_NOT_FOUND),
+            "21000" => Some(CARDINALITY_VIOLATION),
+            "22000" => Some(DATA_EXCEPTION),
+            "22001" => Some(STRING_DATA_RIGHT_TRUNCATION),
+            "22002" => Some(NULL_VALUE_NO_INDICATOR_PARAMETER),
+            "22003" => Some(NUMERIC_VALUE_OUT_OF_RANGE),
+            "22004" => Some(NULL_VALUE_NOT_ALLOWED),
+            "22005" => Some(ERROR_IN_ASSIGNMENT),
+            "22007" => Some(INVALID_DATETIME_FORMAT),
+            "22008" => Some(DATETIME_VALUE_OUT_OF_RANGE),
+            "22009" => Some(INVALID_TIME_ZONE_DISPLACEMENT_VALUE),
+            "2200B" => Some(ESCAPE_CHARACTER_CONFLICT),
+            "2200C" => Some(INVALID_USE_OF_ESCAPE_CHARACTER),
+            "2200D" => Some(INVALID_ESCAPE_OCTET),
+            "2200F" => Some(ZERO_LENGTH_CHARACTER_STRING),
+            "2200G" => Some(MOST_SPECIFIC_TYPE_MISMATCH),
+            "2200H" => Some(SEQUENCE_GENERATOR_LIMIT_EXCEEDED),
+            "2200L" => Some(NOT_AN_XML_DOCUMENT),
+            "2200M" => Some(INVALID_XML_DOCUMENT),
+            "2200N" => Some(INVALID_XML_CONTENT),
+            "2200S" => Some(INVALID_XML_COMMENT),
+            "2200T" => Some(INVALID_XML_PROCESSING_INSTRUCTION),
+            "22010" => Some(INVALID_INDICATOR_PARAMETER_VALUE),
+            "22011" => Some(SUBSTRING_ERROR),
+            "22012" => Some(DIVISION_BY_ZERO),
+            "22013" => Some(INVALID_PRECEDING_OR_FOLLOWING_SIZE),
+            "22014" => Some(INVALID_ARGUMENT_FOR_NTILE),
+            "22015" => Some(INTERVAL_FIELD_OVERFLOW),
+            "22016" => Some(INVALID_ARGUMENT_FOR_NTH_VALUE),
+            "22018" => Some(INVALID_CHARACTER_VALUE_FOR_CAST),
+            "22019" => Some(INVALID_ESCAPE_CHARACTER),
+            "2201B" => Some(INVALID_REGULAR_EXPRESSION),
+            "2201E" => Some(INVALID_ARGUMENT_FOR_LOG),
+            "2201F" => Some(INVALID_ARGUMENT_FOR_POWER_FUNCTION),
+            "2201G" => Some(INVALID_ARGUMENT_FOR_WIDTH_BUCKET_FUNCTION),
+            "2201W" => Some(INVALID_ROW_COUNT_IN_LIMIT_CLAUSE),
+            "2201X" => Some(INVALID_ROW_COUNT_IN_RESULT_OFFSET_CLAUSE),
+            "22021" => Some(CHARACTER_NOT_IN_REPERTOIRE),
+            "22022" => Some(INDICATOR_OVERFLOW),
+            "22023" => Some(INVALID_PARAMETER_VALUE),
+            "22024" => Some(UNTERMINATED_C_STRING),
+            "22025" => Some(INVALID_ESCAPE_SEQUENCE),
+            "22026" => Some(STRING_DATA_LENGTH_MISMATCH),
+            "22027" => Some(TRIM_ERROR),
+            "2202E" => Some(ARRAY_SUBSCRIPT_ERROR),
+            "2202G" => Some(INVALID_TABLESAMPLE_REPEAT),
+            "2202H" => Some(INVALID_TABLESAMPLE_ARGUMENT),
+            "22030" => Some(DUPLICATE_JSON_OBJECT_KEY_VALUE),
+            "22031" => Some(INVALID_ARGUMENT_FOR_SQL_JSON_DATETIME_FUNCTION),
+            "22032" => Some(INVALID_JSON_TEXT),
+            "22033" => Some(INVALID_SQL_JSON_SUBSCRIPT),
+            "22034" => Some(MORE_THAN_ONE_SQL_JSON_ITEM),
+            "22035" => Some(NO_SQL_JSON_ITEM),
+            "22036" => Some(NON_NUMERIC_SQL_JSON_ITEM),
+            "22037" => Some(NON_UNIQUE_KEYS_IN_A_JSON_OBJECT),
+            "22038" => Some(SINGLETON_SQL_JSON_ITEM_REQUIRED),
+            "22039" => Some(SQL_JSON_ARRAY_NOT_FOUND),
+            "2203A" => Some(SQL_JSON_MEMBER_NOT_FOUND),
+            "2203B" => Some(SQL_JSON_NUMBER_NOT_FOUND),
+            "2203C" => Some(SQL_JSON_OBJECT_NOT_FOUND),
+            "2203D" => Some(TOO_MANY_JSON_ARRAY_ELEMENTS),
+            "2203E" => Some(TOO_MANY_JSON_OBJECT_MEMBERS),
+            "2203F" => Some(SQL_JSON_SCALAR_REQUIRED),
+            "22P01" => Some(FLOATING_POINT_EXCEPTION),
+            "22P02" => Some(INVALID_TEXT_REPRESENTATION),
+            "22P03" => Some(INVALID_BINARY_REPRESENTATION),
+            "22P04" => Some(BAD_COPY_FILE_FORMAT),
+            "22P05" => Some(UNTRANSLATABLE_CHARACTER),
+            "22P06" => Some(NONSTANDARD_USE_OF_ESCAPE_CHARACTER),
+            "23000" => Some(INTEGRITY_CONSTRAINT_VIOLATION),
+            "23001" => Some(RESTRICT_VIOLATION),
+            "23502" => Some(NOT_NULL_VIOLATION),
+            "23503" => Some(FOREIGN_KEY_VIOLATION),
+            "23505" => Some(UNIQUE_VIOLATION),
+            "23514" => Some(CHECK_VIOLATION),
+            "23P01" => Some(EXCLUSION_VIOLATION),
+            "24000" => Some(INVALID_CURSOR_STATE),
+            "25000" => Some(INVALID_TRANSACTION_STATE),
+            "25001" => Some(ACTIVE_SQL_TRANSACTION),
+            "25002" => Some(BRANCH_TRANSACTION_ALREADY_ACTIVE),
+            "25003" => Some(INAPPROPRIATE_ACCESS_MODE_FOR_BRANCH_TRANSACTION),
+            "25004" => Some(INAPPROPRIATE_ISOLATION_LEVEL_FOR_BRANCH_TRANSACTION),
+            "25005" => Some(NO_ACTIVE_SQL_TRANSACTION_FOR_BRANCH_TRANSACTION),
+            "25006" => Some(READ_ONLY_SQL_TRANSACTION),
+            "25007" => Some(SCHEMA_AND_DATA_STATEMENT_MIXING_NOT_SUPPORTED),
+            "25008" => Some(HELD_CURSOR_REQUIRES_SAME_ISOLATION_LEVEL),
+            "25P01" => Some(NO_ACTIVE_SQL_TRANSACTION),
+            "25P02" => Some(IN_FAILED_SQL_TRANSACTION),
+            "25P03" => Some(IDLE_IN_TRANSACTION_SESSION_TIMEOUT),
+            "26000" => Some(UNDEFINED_PSTATEMENT),
+            "27000" => Some(TRIGGERED_DATA_CHANGE_VIOLATION),
+            "28000" => Some(INVALID_AUTHORIZATION_SPECIFICATION),
+            "28P01" => Some(INVALID_PASSWORD),
+            "2B000" => Some(DEPENDENT_PRIVILEGE_DESCRIPTORS_STILL_EXIST),
+            "2BP01" => Some(DEPENDENT_OBJECTS_STILL_EXIST),
+            "2D000" => Some(INVALID_TRANSACTION_TERMINATION),
+            "2F000" => Some(SQL_ROUTINE_EXCEPTION),
+            "2F002" => Some(S_R_E_MODIFYING_SQL_DATA_NOT_PERMITTED),
+            "2F003" => Some(S_R_E_PROHIBITED_SQL_STATEMENT_ATTEMPTED),
+            "2F004" => Some(S_R_E_READING_SQL_DATA_NOT_PERMITTED),
+            "2F005" => Some(S_R_E_FUNCTION_EXECUTED_NO_RETURN_STATEMENT),
+            "34000" => Some(UNDEFINED_CURSOR),
+            "38000" => Some(EXTERNAL_ROUTINE_EXCEPTION),
+            "38001" => Some(E_R_E_CONTAINING_SQL_NOT_PERMITTED),
+            "38002" => Some(E_R_E_MODIFYING_SQL_DATA_NOT_PERMITTED),
+            "38003" => Some(E_R_E_PROHIBITED_SQL_STATEMENT_ATTEMPTED),
+            "38004" => Some(E_R_E_READING_SQL_DATA_NOT_PERMITTED),
+            "39000" => Some(EXTERNAL_ROUTINE_INVOCATION_EXCEPTION),
+            "39001" => Some(E_R_I_E_INVALID_SQLSTATE_RETURNED),
+            "39004" => Some(E_R_I_E_NULL_VALUE_NOT_ALLOWED),
+            "39P01" => Some(E_R_I_E_TRIGGER_PROTOCOL_VIOLATED),
+            "39P02" => Some(E_R_I_E_SRF_PROTOCOL_VIOLATED),
+            "39P03" => Some(E_R_I_E_EVENT_TRIGGER_PROTOCOL_VIOLATED),
+            "3B000" => Some(SAVEPOINT_EXCEPTION),
+            "3B001" => Some(S_E_INVALID_SPECIFICATION),
+            "3D000" => Some(UNDEFINED_DATABASE),
+            "3F000" => Some(UNDEFINED_SCHEMA),
+            "40000" => Some(TRANSACTION_ROLLBACK),
+            "40001" => Some(T_R_SERIALIZATION_FAILURE),
+            "40002" => Some(T_R_INTEGRITY_CONSTRAINT_VIOLATION),
+            "40003" => Some(T_R_STATEMENT_COMPLETION_UNKNOWN),
+            "40P01" => Some(T_R_DEADLOCK_DETECTED),
+            "42000" => Some(SYNTAX_ERROR_OR_ACCESS_RULE_VIOLATION),
+            "42501" => Some(INSUFFICIENT_PRIVILEGE),
+            "42601" => Some(SYNTAX_ERROR),
+            "42602" => Some(INVALID_NAME),
+            "42611" => Some(INVALID_COLUMN_DEFINITION),
+            "42622" => Some(NAME_TOO_LONG),
+            "42701" => Some(DUPLICATE_COLUMN),
+            "42702" => Some(AMBIGUOUS_COLUMN),
+            "42703" => Some(UNDEFINED_COLUMN),
+            "42704" => Some(UNDEFINED_OBJECT),
+            "42710" => Some(DUPLICATE_OBJECT),
+            "42712" => Some(DUPLICATE_ALIAS),
+            "42723" => Some(DUPLICATE_FUNCTION),
+            "42725" => Some(AMBIGUOUS_FUNCTION),
+            "42803" => Some(GROUPING_ERROR),
+            "42804" => Some(DATATYPE_MISMATCH),
+            "42809" => Some(WRONG_OBJECT_TYPE),
+            "42830" => Some(INVALID_FOREIGN_KEY),
+            "42846" => Some(CANNOT_COERCE),
+            "42883" => Some(UNDEFINED_FUNCTION),
+            "428C9" => Some(GENERATED_ALWAYS),
+            "42939" => Some(RESERVED_NAME),
+            "42P01" => Some(UNDEFINED_TABLE),
+            "42P02" => Some(UNDEFINED_PARAMETER),
+            "42P03" => Some(DUPLICATE_CURSOR),
+            "42P04" => Some(DUPLICATE_DATABASE),
+            "42P05" => Some(DUPLICATE_PSTATEMENT),
+            "42P06" => Some(DUPLICATE_SCHEMA),
+            "42P07" => Some(DUPLICATE_TABLE),
+            "42P08" => Some(AMBIGUOUS_PARAMETER),
+            "42P09" => Some(AMBIGUOUS_ALIAS),
+            "42P10" => Some(INVALID_COLUMN_REFERENCE),
+            "42P11" => Some(INVALID_CURSOR_DEFINITION),
+            "42P12" => Some(INVALID_DATABASE_DEFINITION),
+            "42P13" => Some(INVALID_FUNCTION_DEFINITION),
+            "42P14" => Some(INVALID_PSTATEMENT_DEFINITION),
+            "42P15" => Some(INVALID_SCHEMA_DEFINITION),
+            "42P16" => Some(INVALID_TABLE_DEFINITION),
+            "42P17" => Some(INVALID_OBJECT_DEFINITION),
+            "42P18" => Some(INDETERMINATE_DATATYPE),
+            "42P19" => Some(INVALID_RECURSION),
+            "42P20" => Some(WINDOWING_ERROR),
+            "42P21" => Some(COLLATION_MISMATCH),
+            "42P22" => Some(INDETERMINATE_COLLATION),
+            "44000" => Some(WITH_CHECK_OPTION_VIOLATION),
+            "53000" => Some(INSUFFICIENT_RESOURCES),
+            "53100" => Some(DISK_FULL),
+            "53200" => Some(OUT_OF_MEMORY),
+            "53300" => Some(TOO_MANY_CONNECTIONS),
+            "53400" => Some(CONFIGURATION_LIMIT_EXCEEDED),
+            "54000" => Some(PROGRAM_LIMIT_EXCEEDED),
+            "54001" => Some(STATEMENT_TOO_COMPLEX),
+            "54011" => Some(TOO_MANY_COLUMNS),
+            "54023" => Some(TOO_MANY_ARGUMENTS),
+            "55000" => Some(OBJECT_NOT_IN_PREREQUISITE_STATE),
+            "55006" => Some(OBJECT_IN_USE),
+            "55P02" => Some(CANT_CHANGE_RUNTIME_PARAM),
+            "55P03" => Some(LOCK_NOT_AVAILABLE),
+            "55P04" => Some(UNSAFE_NEW_ENUM_VALUE_USAGE),
+            "57000" => Some(OPERATOR_INTERVENTION),
+            "57014" => Some(QUERY_CANCELED),
+            "57P01" => Some(ADMIN_SHUTDOWN),
+            "57P02" => Some(CRASH_SHUTDOWN),
+            "57P03" => Some(CANNOT_CONNECT_NOW),
+            "57P04" => Some(DATABASE_DROPPED),
+            "57P05" => Some(IDLE_SESSION_TIMEOUT),
+            "58000" => Some(SYSTEM_ERROR),
+            "58030" => Some(IO_ERROR),
+            "58P01" => Some(UNDEFINED_FILE),
+            "58P02" => Some(DUPLICATE_FILE),
+            "72000" => Some(SNAPSHOT_TOO_OLD),
+            "F0000" => Some(CONFIG_FILE_ERROR),
+            "F0001" => Some(LOCK_FILE_EXISTS),
+            "HV000" => Some(FDW_ERROR),
+            "HV001" => Some(FDW_OUT_OF_MEMORY),
+            "HV002" => Some(FDW_DYNAMIC_PARAMETER_VALUE_NEEDED),
+            "HV004" => Some(FDW_INVALID_DATA_TYPE),
+            "HV005" => Some(FDW_COLUMN_NAME_NOT_FOUND),
+            "HV006" => Some(FDW_INVALID_DATA_TYPE_DESCRIPTORS),
+            "HV007" => Some(FDW_INVALID_COLUMN_NAME),
+            "HV008" => Some(FDW_INVALID_COLUMN_NUMBER),
+            "HV009" => Some(FDW_INVALID_USE_OF_NULL_POINTER),
+            "HV00A" => Some(FDW_INVALID_STRING_FORMAT),
+            "HV00B" => Some(FDW_INVALID_HANDLE),
+            "HV00C" => Some(FDW_INVALID_OPTION_INDEX),
+            "HV00D" => Some(FDW_INVALID_OPTION_NAME),
+            "HV00J" => Some(FDW_OPTION_NAME_NOT_FOUND),
+            "HV00K" => Some(FDW_REPLY_HANDLE),
+            "HV00L" => Some(FDW_UNABLE_TO_CREATE_EXECUTION),
+            "HV00M" => Some(FDW_UNABLE_TO_CREATE_REPLY),
+            "HV00N" => Some(FDW_UNABLE_TO_ESTABLISH_CONNECTION),
+            "HV00P" => Some(FDW_NO_SCHEMAS),
+            "HV00Q" => Some(FDW_SCHEMA_NOT_FOUND),
+            "HV00R" => Some(FDW_TABLE_NOT_FOUND),
+            "HV010" => Some(FDW_FUNCTION_SEQUENCE_ERROR),
+            "HV014" => Some(FDW_TOO_MANY_HANDLES),
+            "HV021" => Some(FDW_INCONSISTENT_DESCRIPTOR_INFORMATION),
+            "HV024" => Some(FDW_INVALID_ATTRIBUTE_VALUE),
+            "HV090" => Some(FDW_INVALID_STRING_LENGTH_OR_BUFFER_LENGTH),
+            "HV091" => Some(FDW_INVALID_DESCRIPTOR_FIELD_IDENTIFIER),
+            "P0000" => Some(PLPGSQL_ERROR),
+            "P0001" => Some(RAISE_EXCEPTION),
+            "P0002" => Some(NO_DATA_FOUND),
+            "P0003" => Some(TOO_MANY_ROWS),
+            "P0004" => Some(ASSERT_FAILURE),
+            "XX000" => Some(INTERNAL_ERROR),
+            "XX001" => Some(DATA_CORRUPTED),
+            "XX002" => Some(INDEX_CORRUPTED),
+            _ => None,
+        }
+    }
+
+    /// Class 00 - Successful Completion
+    pub fn is_successful_completion(&self) -> bool {
+        self.code.starts_with("00")
+    }
+
+    /// Class 01 - Warning
+    pub fn is_warning(&self) -> bool {
+        self.code.starts_with("01")
+    }
+
+    /// Class 02 - No Data (this is also a warning class per the SQL standard)
+    pub fn is_no_data(&self) -> bool {
+        self.code.starts_with("02")
+    }
+
+    /// Class 03 - SQL Statement Not Yet Complete
+    pub fn is_sql_statement_not_yet_complete(&self) -> bool {
+        self.code.starts_with("03")
+    }
+
+    /// Class 08 - Connection Exception
+    pub fn is_connection_exception(&self) -> bool {
+        self.code.starts_with("08")
+    }
+
+    /// Class 09 - Triggered Action Exception
+    pub fn is_triggered_action_exception(&self) -> bool {
+        self.code.starts_with("09")
+    }
+
+    /// Class 0A - Feature Not Supported
+    pub fn is_feature_not_supported(&self) -> bool {
+        self.code.starts_with("0A")
+    }
+
+    /// Class 0B - Invalid Transaction Initiation
+    pub fn is_invalid_transaction_initiation(&self) -> bool {
+        self.code.starts_with("0B")
+    }
+
+    /// Class 0F - Locator Exception
+    pub fn is_locator_exception(&self) -> bool {
+        self.code.starts_with("0F")
+    }
+
+    /// Class 0L - Invalid Grantor
+    pub fn is_invalid_grantor(&self) -> bool {
+        self.code.starts_with("0L")
+    }
+
+    /// Class 0P - Invalid Role Specification
+    pub fn is_invalid_role_specification(&self) -> bool {
+        self.code.starts_with("0P")
+    }
+
+    /// Class 0Z - Diagnostics Exception
+    pub fn is_diagnostics_exception(&self) -> bool {
+        self.code.starts_with("0Z")
+    }
+
+    /// Class 20 - Case Not Found
+    pub fn is_case_not_found(&self) -> bool {
+        self.code.starts_with("20")
+    }
+
+    /// Class 21 - Cardinality Violation
+    pub fn is_cardinality_violation(&self) -> bool {
+        self.code.starts_with("21")
+    }
+
+    /// Class 22 - Data Exception
+    pub fn is_data_exception(&self) -> bool {
+        self.code.starts_with("22")
+    }
+
+    /// Class 23 - Integrity Constraint Violation
+    pub fn is_integrity_constraint_violation(&self) -> bool {
+        self.code.starts_with("23")
+    }
+
+    /// Class 24 - Invalid Cursor State
+    pub fn is_invalid_cursor_state(&self) -> bool {
+        self.code.starts_with("24")
+    }
+
+    /// Class 25 - Invalid Transaction State
+    pub fn is_invalid_transaction_state(&self) -> bool {
+        self.code.starts_with("25")
+    }
+
+    /// Class 26 - Invalid SQL Statement Name
+    pub fn is_invalid_sql_statement_name(&self) -> bool {
+        self.code.starts_with("26")
+    }
+
+    /// Class 27 - Triggered Data Change Violation
+    pub fn is_triggered_data_change_violation(&self) -> bool {
+        self.code.starts_with("27")
+    }
+
+    /// Class 28 - Invalid Authorization Specification
+    pub fn is_invalid_authorization_specification(&self) -> bool {
+        self.code.starts_with("28")
+    }
+
+    /// Class 2B - Dependent Privilege Descriptors Still Exist
+    pub fn is_dependent_privilege_descriptors_still_exist(&self) -> bool {
+        self.code.starts_with("2B")
+    }
+
+    /// Class 2D - Invalid Transaction Termination
+    pub fn is_invalid_transaction_termination(&self) -> bool {
+        self.code.starts_with("2D")
+    }
+
+    /// Class 2F - SQL Routine Exception
+    pub fn is_sql_routine_exception(&self) -> bool {
+        self.code.starts_with("2F")
+    }
+
+    /// Class 34 - Invalid Cursor Name
+    pub fn is_invalid_cursor_name(&self) -> bool {
+        self.code.starts_with("34")
+    }
+
+    /// Class 38 - External Routine Exception
+    pub fn is_external_routine_exception(&self) -> bool {
+        self.code.starts_with("38")
+    }
+
+    /// Class 39 - External Routine Invocation Exception
+    pub fn is_external_routine_invocation_exception(&self) -> bool {
+        self.code.starts_with("39")
+    }
+
+    /// Class 3B - Savepoint Exception
+    pub fn is_savepoint_exception(&self) -> bool {
+        self.code.starts_with("3B")
+    }
+
+    /// Class 3D - Invalid Catalog Name
+    pub fn is_invalid_catalog_name(&self) -> bool {
+        self.code.starts_with("3D")
+    }
+
+    /// Class 3F - Invalid Schema Name
+    pub fn is_invalid_schema_name(&self) -> bool {
+        self.code.starts_with("3F")
+    }
+
+    /// Class 40 - Transaction Rollback
+    pub fn is_transaction_rollback(&self) -> bool {
+        self.code.starts_with("40")
+    }
+
+    /// Class 42 - Syntax Error or Access Rule Violation
+    pub fn is_syntax_error_or_access_rule_violation(&self) -> bool {
+        self.code.starts_with("42")
+    }
+
+    /// Class 44 - WITH CHECK OPTION Violation
+    pub fn is_with_check_option_violation(&self) -> bool {
+        self.code.starts_with("44")
+    }
+
+    /// Class 53 - Insufficient Resources
+    pub fn is_insufficient_resources(&self) -> bool {
+        self.code.starts_with("53")
+    }
+
+    /// Class 54 - Program Limit Exceeded
+    pub fn is_program_limit_exceeded(&self) -> bool {
+        self.code.starts_with("54")
+    }
+
+    /// Class 55 - Object Not In Prerequisite State
+    pub fn is_object_not_in_prerequisite_state(&self) -> bool {
+        self.code.starts_with("55")
+    }
+
+    /// Class 57 - Operator Intervention
+    pub fn is_operator_intervention(&self) -> bool {
+        self.code.starts_with("57")
+    }
+
+    /// Class 58 - System Error (errors external to PostgreSQL itself)
+    pub fn is_system_error(&self) -> bool {
+        self.code.starts_with("58")
+    }
+
+    /// Class 72 - Snapshot Failure
+    pub fn is_snapshot_failure(&self) -> bool {
+        self.code.starts_with("72")
+    }
+
+    /// Class F0 - Configuration File Error
+    pub fn is_configuration_file_error(&self) -> bool {
+        self.code.starts_with("F0")
+    }
+
+    /// Class HV - Foreign Data Wrapper Error (SQL/MED)
+    pub fn is_foreign_data_wrapper_error(&self) -> bool {
+        self.code.starts_with("HV")
+    }
+
+    /// Class P0 - PL/pgSQL Error
+    pub fn is_pl_pgsql_error(&self) -> bool {
+        self.code.starts_with("P0")
+    }
+
+    /// Class XX - Internal Error
+    pub fn is_internal_error(&self) -> bool {
+        self.code.starts_with("XX")
+    }
+}
+
+/// A SQLSTATE error code, as an enum.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SqlState {
+    /// successful completion
+    SuccessfulCompletion,
+    /// warning
+    Warning,
+    /// null value eliminated in set function
+    WarningNullValueEliminatedInSetFunction,
+    /// string data right truncation
+    WarningStringDataRightTruncation,
+    /// privilege not revoked
+    WarningPrivilegeNotRevoked,
+    /// privilege not granted
+    WarningPrivilegeNotGranted,
+    /// implicit zero bit padding
+    WarningImplicitZeroBitPadding,
+    /// dynamic result sets returned
+    WarningDynamicResultSetsReturned,
+    /// deprecated feature
+    WarningDeprecatedFeature,
+    /// no data
+    NoData,
+    /// no additional dynamic result sets returned
+    NoAdditionalDynamicResultSetsReturned,
+    /// sql statement not yet complete
+    SqlStatementNotYetComplete,
+    /// connection exception
+    ConnectionException,
+    /// sqlclient unable to establish sqlconnection
+    SqlclientUnableToEstablishSqlconnection,
+    /// connection does not exist
+    ConnectionDoesNotExist,
+    /// sqlserver rejected establishment of sqlconnection
+    SqlserverRejectedEstablishmentOfSqlconnection,
+    /// connection failure
+    ConnectionFailure,
+    /// transaction resolution unknown
+    TransactionResolutionUnknown,
+    /// protocol violation
+    ProtocolViolation,
+    /// triggered action exception
+    TriggeredActionException,
+    /// feature not supported
+    FeatureNotSupported,
+    /// invalid transaction initiation
+    InvalidTransactionInitiation,
+    /// locator exception
+    LocatorException,
+    /// invalid locator specification
+    LEInvalidSpecification,
+    /// invalid grantor
+    InvalidGrantor,
+    /// invalid grant operation
+    InvalidGrantOperation,
+    /// invalid role specification
+    InvalidRoleSpecification,
+    /// diagnostics exception
+    DiagnosticsException,
+    /// stacked diagnostics accessed without active handler
+    StackedDiagnosticsAccessedWithoutActiveHandler,
+    /// case not found
+    CaseNotFound,
+    /// cardinality violation
+    CardinalityViolation,
+    /// data exception
+    DataException,
+    /// string data right truncation
+    StringDataRightTruncation,
+    /// null value no indicator parameter
+    NullValueNoIndicatorParameter,
+    /// numeric value out of range
+    NumericValueOutOfRange,
+    /// null value not allowed
+    NullValueNotAllowed,
+    /// error in assignment
+    ErrorInAssignment,
+    /// invalid datetime format
+    InvalidDatetimeFormat,
+    DatetimeValueOutOfRange,
+    /// invalid time zone displacement value
+    InvalidTimeZoneDisplacementValue,
+    /// escape character conflict
+    EscapeCharacterConflict,
+    /// invalid use of escape character
+    InvalidUseOfEscapeCharacter,
+    /// invalid escape octet
+    InvalidEscapeOctet,
+    /// zero length character string
+    ZeroLengthCharacterString,
+    /// most specific type mismatch
+    MostSpecificTypeMismatch,
+    /// sequence generator limit exceeded
+    SequenceGeneratorLimitExceeded,
+    /// not an xml document
+    NotAnXmlDocument,
+    /// invalid xml document
+    InvalidXmlDocument,
+    /// invalid xml content
+    InvalidXmlContent,
+    /// invalid xml comment
+    InvalidXmlComment,
+    /// invalid xml processing instruction
+    InvalidXmlProcessingInstruction,
+    /// invalid indicator parameter value
+    InvalidIndicatorParameterValue,
+    /// substring error
+    SubstringError,
+    /// division by zero
+    DivisionByZero,
+    /// invalid preceding or following size
+    InvalidPrecedingOrFollowingSize,
+    /// invalid argument for ntile function
+    InvalidArgumentForNtile,
+    /// interval field overflow
+    IntervalFieldOverflow,
+    /// invalid argument for nth value function
+    InvalidArgumentForNthValue,
+    /// invalid character value for cast
+    InvalidCharacterValueForCast,
+    /// invalid escape character
+    InvalidEscapeCharacter,
+    /// invalid regular expression
+    InvalidRegularExpression,
+    /// invalid argument for logarithm
+    InvalidArgumentForLog,
+    /// invalid argument for power function
+    InvalidArgumentForPowerFunction,
+    /// invalid argument for width bucket function
+    InvalidArgumentForWidthBucketFunction,
+    /// invalid row count in limit clause
+    InvalidRowCountInLimitClause,
+    /// invalid row count in result offset clause
+    InvalidRowCountInResultOffsetClause,
+    /// character not in repertoire
+    CharacterNotInRepertoire,
+    /// indicator overflow
+    IndicatorOverflow,
+    /// invalid parameter value
+    InvalidParameterValue,
+    /// unterminated c string
+    UnterminatedCString,
+    /// invalid escape sequence
+    InvalidEscapeSequence,
+    /// string data length mismatch
+    StringDataLengthMismatch,
+    /// trim error
+    TrimError,
+    /// array subscript error
+    ArraySubscriptError,
+    /// invalid tablesample repeat
+    InvalidTablesampleRepeat,
+    /// invalid tablesample argument
+    InvalidTablesampleArgument,
+    /// duplicate json object key value
+    DuplicateJsonObjectKeyValue,
+    /// invalid argument for sql json datetime function
+    InvalidArgumentForSqlJsonDatetimeFunction,
+    /// invalid json text
+    InvalidJsonText,
+    /// invalid sql json subscript
+    InvalidSqlJsonSubscript,
+    /// more than one sql json item
+    MoreThanOneSqlJsonItem,
+    /// no sql json item
+    NoSqlJsonItem,
+    /// non numeric sql json item
+    NonNumericSqlJsonItem,
+    /// non unique keys in a json object
+    NonUniqueKeysInAJsonObject,
+    /// singleton sql json item required
+    SingletonSqlJsonItemRequired,
+    /// sql json array not found
+    SqlJsonArrayNotFound,
+    /// sql json member not found
+    SqlJsonMemberNotFound,
+    /// sql json number not found
+    SqlJsonNumberNotFound,
+    /// sql json object not found
+    SqlJsonObjectNotFound,
+    /// too many json array elements
+    TooManyJsonArrayElements,
+    /// too many json object members
+    TooManyJsonObjectMembers,
+    /// sql json scalar required
+    SqlJsonScalarRequired,
+    /// floating point exception
+    FloatingPointException,
+    /// invalid text representation
+    InvalidTextRepresentation,
+    /// invalid binary representation
+    InvalidBinaryRepresentation,
+    /// bad copy file format
+    BadCopyFileFormat,
+    /// untranslatable character
+    UntranslatableCharacter,
+    /// nonstandard use of escape character
+    NonstandardUseOfEscapeCharacter,
+    /// integrity constraint violation
+    IntegrityConstraintViolation,
+    /// restrict violation
+    RestrictViolation,
+    /// not null violation
+    NotNullViolation,
+    /// foreign key violation
+    ForeignKeyViolation,
+    /// unique violation
+    UniqueViolation,
+    /// check violation
+    CheckViolation,
+    /// exclusion violation
+    ExclusionViolation,
+    /// invalid cursor state
+    InvalidCursorState,
+    /// invalid transaction state
+    InvalidTransactionState,
+    /// active sql transaction
+    ActiveSqlTransaction,
+    /// branch transaction already active
+    BranchTransactionAlreadyActive,
+    /// inappropriate access mode for branch transaction
+    InappropriateAccessModeForBranchTransaction,
+    /// inappropriate isolation level for branch transaction
+    InappropriateIsolationLevelForBranchTransaction,
+    /// no active sql transaction for branch transaction
+    NoActiveSqlTransactionForBranchTransaction,
+    /// read only sql transaction
+    ReadOnlySqlTransaction,
+    /// schema and data statement mixing not supported
+    SchemaAndDataStatementMixingNotSupported,
+    /// held cursor requires same isolation level
+    HeldCursorRequiresSameIsolationLevel,
+    /// no active sql transaction
+    NoActiveSqlTransaction,
+    /// in failed sql transaction
+    InFailedSqlTransaction,
+    /// idle in transaction session timeout
+    IdleInTransactionSessionTimeout,
+    UndefinedPstatement,
+    /// triggered data change violation
+    TriggeredDataChangeViolation,
+    /// invalid authorization specification
+    InvalidAuthorizationSpecification,
+    /// invalid password
+    InvalidPassword,
+    /// dependent privilege descriptors still exist
+    DependentPrivilegeDescriptorsStillExist,
+    /// dependent objects still exist
+    DependentObjectsStillExist,
+    /// invalid transaction termination
+    InvalidTransactionTermination,
+    /// sql routine exception
+    SqlRoutineException,
+    /// modifying sql data not permitted
+    SREModifyingSqlDataNotPermitted,
+    /// prohibited sql statement attempted
+    SREProhibitedSqlStatementAttempted,
+    /// reading sql data not permitted
+    SREReadingSqlDataNotPermitted,
+    /// function executed no return statement
+    SREFunctionExecutedNoReturnStatement,
+    UndefinedCursor,
+    /// external routine exception
+    ExternalRoutineException,
+    /// containing sql not permitted
+    EREContainingSqlNotPermitted,
+    /// modifying sql data not permitted
+    EREModifyingSqlDataNotPermitted,
+    /// prohibited sql statement attempted
+    EREProhibitedSqlStatementAttempted,
+    /// reading sql data not permitted
+    EREReadingSqlDataNotPermitted,
+    /// external routine invocation exception
+    ExternalRoutineInvocationException,
+    /// invalid sqlstate returned
+    ERIEInvalidSqlstateReturned,
+    /// null value not allowed
+    ERIENullValueNotAllowed,
+    /// trigger protocol violated
+    ERIETriggerProtocolViolated,
+    /// srf protocol violated
+    ERIESrfProtocolViolated,
+    /// event trigger protocol violated
+    ERIEEventTriggerProtocolViolated,
+    /// savepoint exception
+    SavepointException,
+    /// invalid savepoint specification
+    SEInvalidSpecification,
+    UndefinedDatabase,
+    UndefinedSchema,
+    /// transaction rollback
+    TransactionRollback,
+    /// serialization failure
+    TRSerializationFailure,
+    /// transaction integrity constraint violation
+    TRIntegrityConstraintViolation,
+    /// statement completion unknown
+    TRStatementCompletionUnknown,
+    /// deadlock detected
+    TRDeadlockDetected,
+    /// syntax error or access rule violation
+    SyntaxErrorOrAccessRuleViolation,
+    /// insufficient privilege
+    InsufficientPrivilege,
+    /// syntax error
+    SyntaxError,
+    /// invalid name
+    InvalidName,
+    /// invalid column definition
+    InvalidColumnDefinition,
+    /// name too long
+    NameTooLong,
+    /// duplicate column
+    DuplicateColumn,
+    /// ambiguous column
+    AmbiguousColumn,
+    /// undefined column
+    UndefinedColumn,
+    /// undefined object
+    UndefinedObject,
+    /// duplicate object
+    DuplicateObject,
+    /// duplicate alias
+    DuplicateAlias,
+    /// duplicate function
+    DuplicateFunction,
+    /// ambiguous function
+    AmbiguousFunction,
+    /// grouping error
+    GroupingError,
+    /// datatype mismatch
+    DatatypeMismatch,
+    /// wrong object type
+    WrongObjectType,
+    /// invalid foreign key
+    InvalidForeignKey,
+    /// cannot coerce
+    CannotCoerce,
+    /// undefined function
+    UndefinedFunction,
+    /// generated always
+    GeneratedAlways,
+    /// reserved name
+    ReservedName,
+    /// undefined table
+    UndefinedTable,
+    /// undefined parameter
+    UndefinedParameter,
+    /// duplicate cursor
+    DuplicateCursor,
+    /// duplicate database
+    DuplicateDatabase,
+    /// duplicate prepared statement
+    DuplicatePstatement,
+    /// duplicate schema
+    DuplicateSchema,
+    /// duplicate table
+    DuplicateTable,
+    /// ambiguous parameter
+    AmbiguousParameter,
+    /// ambiguous alias
+    AmbiguousAlias,
+    /// invalid column reference
+    InvalidColumnReference,
+    /// invalid cursor definition
+    InvalidCursorDefinition,
+    /// invalid database definition
+    InvalidDatabaseDefinition,
+    /// invalid function definition
+    InvalidFunctionDefinition,
+    /// invalid prepared statement definition
+    InvalidPstatementDefinition,
+    /// invalid schema definition
+    InvalidSchemaDefinition,
+    /// invalid table definition
+    InvalidTableDefinition,
+    /// invalid object definition
+    InvalidObjectDefinition,
+    /// indeterminate datatype
+    IndeterminateDatatype,
+    /// invalid recursion
+    InvalidRecursion,
+    /// windowing error
+    WindowingError,
+    /// collation mismatch
+    CollationMismatch,
+    /// indeterminate collation
+    IndeterminateCollation,
+    /// with check option violation
+    WithCheckOptionViolation,
+    /// insufficient resources
+    InsufficientResources,
+    /// disk full
+    DiskFull,
+    /// out of memory
+    OutOfMemory,
+    /// too many connections
+    TooManyConnections,
+    /// configuration limit exceeded
+    ConfigurationLimitExceeded,
+    /// program limit exceeded
+    ProgramLimitExceeded,
+    /// statement too complex
+    StatementTooComplex,
+    /// too many columns
+    TooManyColumns,
+    /// too many arguments
+    TooManyArguments,
+    /// object not in prerequisite state
+    ObjectNotInPrerequisiteState,
+    /// object in use
+    ObjectInUse,
+    /// cant change runtime param
+    CantChangeRuntimeParam,
+    /// lock not available
+    LockNotAvailable,
+    /// unsafe new enum value usage
+    UnsafeNewEnumValueUsage,
+    /// operator intervention
+    OperatorIntervention,
+    /// query canceled
+    QueryCanceled,
+    /// admin shutdown
+    AdminShutdown,
+    /// crash shutdown
+    CrashShutdown,
+    /// cannot connect now
+    CannotConnectNow,
+    /// database dropped
+    DatabaseDropped,
+    /// idle session timeout
+    IdleSessionTimeout,
+    /// system error
+    SystemError,
+    /// io error
+    IoError,
+    /// undefined file
+    UndefinedFile,
+    /// duplicate file
+    DuplicateFile,
+    /// snapshot too old
+    SnapshotTooOld,
+    /// config file error
+    ConfigFileError,
+    /// lock file exists
+    LockFileExists,
+    /// fdw error
+    FdwError,
+    /// fdw out of memory
+    FdwOutOfMemory,
+    /// fdw dynamic parameter value needed
+    FdwDynamicParameterValueNeeded,
+    /// fdw invalid data type
+    FdwInvalidDataType,
+    /// fdw column name not found
+    FdwColumnNameNotFound,
+    /// fdw invalid data type descriptors
+    FdwInvalidDataTypeDescriptors,
+    /// fdw invalid column name
+    FdwInvalidColumnName,
+    /// fdw invalid column number
+    FdwInvalidColumnNumber,
+    /// fdw invalid use of null pointer
+    FdwInvalidUseOfNullPointer,
+    /// fdw invalid string format
+    FdwInvalidStringFormat,
+    /// fdw invalid handle
+    FdwInvalidHandle,
+    /// fdw invalid option index
+    FdwInvalidOptionIndex,
+    /// fdw invalid option name
+    FdwInvalidOptionName,
+    /// fdw option name not found
+    FdwOptionNameNotFound,
+    /// fdw reply handle
+    FdwReplyHandle,
+    /// fdw unable to create execution
+    FdwUnableToCreateExecution,
+    /// fdw unable to create reply
+    FdwUnableToCreateReply,
+    /// fdw unable to establish connection
+    FdwUnableToEstablishConnection,
+    /// fdw no schemas
+    FdwNoSchemas,
+    /// fdw schema not found
+    FdwSchemaNotFound,
+    /// fdw table not found
+    FdwTableNotFound,
+    /// fdw function sequence error
+    FdwFunctionSequenceError,
+    /// fdw too many handles
+    FdwTooManyHandles,
+    /// fdw inconsistent descriptor information
+    FdwInconsistentDescriptorInformation,
+    /// fdw invalid attribute value
+    FdwInvalidAttributeValue,
+    /// fdw invalid string length or buffer length
+    FdwInvalidStringLengthOrBufferLength,
+    /// fdw invalid descriptor field identifier
+    FdwInvalidDescriptorFieldIdentifier,
+    /// plpgsql error
+    PlpgsqlError,
+    /// raise exception
+    RaiseException,
+    /// no data found
+    NoDataFound,
+    /// too many rows
+    TooManyRows,
+    /// assert failure
+    AssertFailure,
+    /// internal error
+    InternalError,
+    /// data corrupted
+    DataCorrupted,
+    /// index corrupted
+    IndexCorrupted,
+}
+
+impl SqlState {
+    /// Creates a `SqlState` from its error code, returns `None` for an unknown code.
+    pub fn from_code(s: &str) -> Option<SqlState> {
+        match s {
+            "00000" => Some(Self::SuccessfulCompletion),
+            "01000" => Some(Self::Warning),
+            "01003" => Some(Self::WarningNullValueEliminatedInSetFunction),
+            "01004" => Some(Self::WarningStringDataRightTruncation),
+            "01006" => Some(Self::WarningPrivilegeNotRevoked),
+            "01007" => Some(Self::WarningPrivilegeNotGranted),
+            "01008" => Some(Self::WarningImplicitZeroBitPadding),
+            "0100C" => Some(Self::WarningDynamicResultSetsReturned),
+            "01P01" => Some(Self::WarningDeprecatedFeature),
+            "02000" => Some(Self::NoData),
+            "02001" => Some(Self::NoAdditionalDynamicResultSetsReturned),
+            "03000" => Some(Self::SqlStatementNotYetComplete),
+            "08000" => Some(Self::ConnectionException),
+            "08001" => Some(Self::SqlclientUnableToEstablishSqlconnection),
+            "08003" => Some(Self::ConnectionDoesNotExist),
+            "08004" => Some(Self::SqlserverRejectedEstablishmentOfSqlconnection),
+            "08006" => Some(Self::ConnectionFailure),
+            "08007" => Some(Self::TransactionResolutionUnknown),
+            "08P01" => Some(Self::ProtocolViolation),
+            "09000" => Some(Self::TriggeredActionException),
+            "0A000" => Some(Self::FeatureNotSupported),
+            "0B000" => Some(Self::InvalidTransactionInitiation),
+            "0F000" => Some(Self::LocatorException),
+            "0F001" => Some(Self::LEInvalidSpecification),
+            "0L000" => Some(Self::InvalidGrantor),
+            "0LP01" => Some(Self::InvalidGrantOperation),
+            "0P000" => Some(Self::InvalidRoleSpecification),
+            "0Z000" => Some(Self::DiagnosticsException),
+            "0Z002" => Some(Self::StackedDiagnosticsAccessedWithoutActiveHandler),
+            "20000" => Some(Self::CaseNotFound),
+            "21000" => Some(Self::CardinalityViolation),
+            "22000" => Some(Self::DataException),
+            "22001" => Some(Self::StringDataRightTruncation),
+            "22002" => Some(Self::NullValueNoIndicatorParameter),
+            "22003" => Some(Self::NumericValueOutOfRange),
+            "22004" => Some(Self::NullValueNotAllowed),
+            "22005" => Some(Self::ErrorInAssignment),
+            "22007" => Some(Self::InvalidDatetimeFormat),
+            "22008" => Some(Self::DatetimeValueOutOfRange),
+            "22009" => Some(Self::InvalidTimeZoneDisplacementValue),
+            "2200B" => Some(Self::EscapeCharacterConflict),
+            "2200C" => Some(Self::InvalidUseOfEscapeCharacter),
+            "2200D" => Some(Self::InvalidEscapeOctet),
+            "2200F" => Some(Self::ZeroLengthCharacterString),
+            "2200G" => Some(Self::MostSpecificTypeMismatch),
+            "2200H" => Some(Self::SequenceGeneratorLimitExceeded),
+            "2200L" => Some(Self::NotAnXmlDocument),
+            "2200M" => Some(Self::InvalidXmlDocument),
+            "2200N" => Some(Self::InvalidXmlContent),
+            "2200S" => Some(Self::InvalidXmlComment),
+            "2200T" => Some(Self::InvalidXmlProcessingInstruction),
+            "22010" => Some(Self::InvalidIndicatorParameterValue),
+            "22011" => Some(Self::SubstringError),
+            "22012" => Some(Self::DivisionByZero),
+            "22013" => Some(Self::InvalidPrecedingOrFollowingSize),
+            "22014" => Some(Self::InvalidArgumentForNtile),
+            "22015" => Some(Self::IntervalFieldOverflow),
+            "22016" => Some(Self::InvalidArgumentForNthValue),
+            "22018" => Some(Self::InvalidCharacterValueForCast),
+            "22019" => Some(Self::InvalidEscapeCharacter),
+            "2201B" => Some(Self::InvalidRegularExpression),
+            "2201E" => Some(Self::InvalidArgumentForLog),
+            "2201F" => Some(Self::InvalidArgumentForPowerFunction),
+            "2201G" => Some(Self::InvalidArgumentForWidthBucketFunction),
+            "2201W" => Some(Self::InvalidRowCountInLimitClause),
+            "2201X" => Some(Self::InvalidRowCountInResultOffsetClause),
+            "22021" => Some(Self::CharacterNotInRepertoire),
+            "22022" => Some(Self::IndicatorOverflow),
+            "22023" => Some(Self::InvalidParameterValue),
+            "22024" => Some(Self::UnterminatedCString),
+            "22025" => Some(Self::InvalidEscapeSequence),
+            "22026" => Some(Self::StringDataLengthMismatch),
+            "22027" => Some(Self::TrimError),
+            "2202E" => Some(Self::ArraySubscriptError),
+            "2202G" => Some(Self::InvalidTablesampleRepeat),
+            "2202H" => Some(Self::InvalidTablesampleArgument),
+            "22030" => Some(Self::DuplicateJsonObjectKeyValue),
+            "22031" => Some(Self::InvalidArgumentForSqlJsonDatetimeFunction),
+            "22032" => Some(Self::InvalidJsonText),
+            "22033" => Some(Self::InvalidSqlJsonSubscript),
+            "22034" => Some(Self::MoreThanOneSqlJsonItem),
+            "22035" => Some(Self::NoSqlJsonItem),
+            "22036" => Some(Self::NonNumericSqlJsonItem),
+            "22037" => Some(Self::NonUniqueKeysInAJsonObject),
+            "22038" => Some(Self::SingletonSqlJsonItemRequired),
+            "22039" => Some(Self::SqlJsonArrayNotFound),
+            "2203A" => Some(Self::SqlJsonMemberNotFound),
+            "2203B" => Some(Self::SqlJsonNumberNotFound),
+            "2203C" => Some(Self::SqlJsonObjectNotFound),
+            "2203D" => Some(Self::TooManyJsonArrayElements),
+            "2203E" => Some(Self::TooManyJsonObjectMembers),
+            "2203F" => Some(Self::SqlJsonScalarRequired),
+            "22P01" => Some(Self::FloatingPointException),
+            "22P02" => Some(Self::InvalidTextRepresentation),
+            "22P03" => Some(Self::InvalidBinaryRepresentation),
+            "22P04" => Some(Self::BadCopyFileFormat),
+            "22P05" => Some(Self::UntranslatableCharacter),
+            "22P06" => Some(Self::NonstandardUseOfEscapeCharacter),
+            "23000" => Some(Self::IntegrityConstraintViolation),
+            "23001" => Some(Self::RestrictViolation),
+            "23502" => Some(Self::NotNullViolation),
+            "23503" => Some(Self::ForeignKeyViolation),
+            "23505" => Some(Self::UniqueViolation),
+            "23514" => Some(Self::CheckViolation),
+            "23P01" => Some(Self::ExclusionViolation),
+            "24000" => Some(Self::InvalidCursorState),
+            "25000" => Some(Self::InvalidTransactionState),
+            "25001" => Some(Self::ActiveSqlTransaction),
+            "25002" => Some(Self::BranchTransactionAlreadyActive),
+            "25003" => Some(Self::InappropriateAccessModeForBranchTransaction),
+            "25004" => Some(Self::InappropriateIsolationLevelForBranchTransaction),
+            "25005" => Some(Self::NoActiveSqlTransactionForBranchTransaction),
+            "25006" => Some(Self::ReadOnlySqlTransaction),
+            "25007" => Some(Self::SchemaAndDataStatementMixingNotSupported),
+            "25008" => Some(Self::HeldCursorRequiresSameIsolationLevel),
+            "25P01" => Some(Self::NoActiveSqlTransaction),
+            "25P02" => Some(Self::InFailedSqlTransaction),
+            "25P03" => Some(Self::IdleInTransactionSessionTimeout),
+            "26000" => Some(Self::UndefinedPstatement),
+            "27000" => Some(Self::TriggeredDataChangeViolation),
+            "28000" => Some(Self::InvalidAuthorizationSpecification),
+            "28P01" => Some(Self::InvalidPassword),
+            "2B000" => Some(Self::DependentPrivilegeDescriptorsStillExist),
+            "2BP01" => Some(Self::DependentObjectsStillExist),
+            "2D000" => Some(Self::InvalidTransactionTermination),
+            "2F000" => Some(Self::SqlRoutineException),
+            "2F002" => Some(Self::SREModifyingSqlDataNotPermitted),
+            "2F003" => Some(Self::SREProhibitedSqlStatementAttempted),
+            "2F004" => Some(Self::SREReadingSqlDataNotPermitted),
+            "2F005" => Some(Self::SREFunctionExecutedNoReturnStatement),
+            "34000" => Some(Self::UndefinedCursor),
+            "38000" => Some(Self::ExternalRoutineException),
+            "38001" => Some(Self::EREContainingSqlNotPermitted),
+            "38002" => Some(Self::EREModifyingSqlDataNotPermitted),
+            "38003" => Some(Self::EREProhibitedSqlStatementAttempted),
+            "38004" => Some(Self::EREReadingSqlDataNotPermitted),
+            "39000" => Some(Self::ExternalRoutineInvocationException),
+            "39001" => Some(Self::ERIEInvalidSqlstateReturned),
+            "39004" => Some(Self::ERIENullValueNotAllowed),
+            "39P01" => Some(Self::ERIETriggerProtocolViolated),
+            "39P02" => Some(Self::ERIESrfProtocolViolated),
+            "39P03" => Some(Self::ERIEEventTriggerProtocolViolated),
+            "3B000" => Some(Self::SavepointException),
+            "3B001" => Some(Self::SEInvalidSpecification),
+            "3D000" => Some(Self::UndefinedDatabase),
+            "3F000" => Some(Self::UndefinedSchema),
+            "40000" => Some(Self::TransactionRollback),
+            "40001" => Some(Self::TRSerializationFailure),
+            "40002" => Some(Self::TRIntegrityConstraintViolation),
+            "40003" => Some(Self::TRStatementCompletionUnknown),
+            "40P01" => Some(Self::TRDeadlockDetected),
+            "42000" => Some(Self::SyntaxErrorOrAccessRuleViolation),
+            "42501" => Some(Self::InsufficientPrivilege),
+            "42601" => Some(Self::SyntaxError),
+            "42602" => Some(Self::InvalidName),
+            "42611" => Some(Self::InvalidColumnDefinition),
+            "42622" => Some(Self::NameTooLong),
+            "42701" => Some(Self::DuplicateColumn),
+            "42702" => Some(Self::AmbiguousColumn),
+            "42703" => Some(Self::UndefinedColumn),
+            "42704" => Some(Self::UndefinedObject),
+            "42710" => Some(Self::DuplicateObject),
+            "42712" => Some(Self::DuplicateAlias),
+            "42723" => Some(Self::DuplicateFunction),
+            "42725" => Some(Self::AmbiguousFunction),
+            "42803" => Some(Self::GroupingError),
+            "42804" => Some(Self::DatatypeMismatch),
+            "42809" => Some(Self::WrongObjectType),
+            "42830" => Some(Self::InvalidForeignKey),
+            "42846" => Some(Self::CannotCoerce),
+            "42883" => Some(Self::UndefinedFunction),
+            "428C9" => Some(Self::GeneratedAlways),
+            "42939" => Some(Self::ReservedName),
+            "42P01" => Some(Self::UndefinedTable),
+            "42P02" => Some(Self::UndefinedParameter),
+            "42P03" => Some(Self::DuplicateCursor),
+            "42P04" => Some(Self::DuplicateDatabase),
+            "42P05" => Some(Self::DuplicatePstatement),
+            "42P06" => Some(Self::DuplicateSchema),
+            "42P07" => Some(Self::DuplicateTable),
+            "42P08" => Some(Self::AmbiguousParameter),
+            "42P09" => Some(Self::AmbiguousAlias),
+            "42P10" => Some(Self::InvalidColumnReference),
+            "42P11" => Some(Self::InvalidCursorDefinition),
+            "42P12" => Some(Self::InvalidDatabaseDefinition),
+            "42P13" => Some(Self::InvalidFunctionDefinition),
+            "42P14" => Some(Self::InvalidPstatementDefinition),
+            "42P15" => Some(Self::InvalidSchemaDefinition),
+            "42P16" => Some(Self::InvalidTableDefinition),
+            "42P17" => Some(Self::InvalidObjectDefinition),
+            "42P18" => Some(Self::IndeterminateDatatype),
+            "42P19" => Some(Self::InvalidRecursion),
+            "42P20" => Some(Self::WindowingError),
+            "42P21" => Some(Self::CollationMismatch),
+            "42P22" => Some(Self::IndeterminateCollation),
+            "44000" => Some(Self::WithCheckOptionViolation),
+            "53000" => Some(Self::InsufficientResources),
+            "53100" => Some(Self::DiskFull),
+            "53200" => Some(Self::OutOfMemory),
+            "53300" => Some(Self::TooManyConnections),
+            "53400" => Some(Self::ConfigurationLimitExceeded),
+            "54000" => Some(Self::ProgramLimitExceeded),
+            "54001" => Some(Self::StatementTooComplex),
+            "54011" => Some(Self::TooManyColumns),
+            "54023" => Some(Self::TooManyArguments),
+            "55000" => Some(Self::ObjectNotInPrerequisiteState),
+            "55006" => Some(Self::ObjectInUse),
+            "55P02" => Some(Self::CantChangeRuntimeParam),
+            "55P03" => Some(Self::LockNotAvailable),
+            "55P04" => Some(Self::UnsafeNewEnumValueUsage),
+            "57000" => Some(Self::OperatorIntervention),
+            "57014" => Some(Self::QueryCanceled),
+            "57P01" => Some(Self::AdminShutdown),
+            "57P02" => Some(Self::CrashShutdown),
+            "57P03" => Some(Self::CannotConnectNow),
+            "57P04" => Some(Self::DatabaseDropped),
+            "57P05" => Some(Self::IdleSessionTimeout),
+            "58000" => Some(Self::SystemError),
+            "58030" => Some(Self::IoError),
+            "58P01" => Some(Self::UndefinedFile),
+            "58P02" => Some(Self::DuplicateFile),
+            "72000" => Some(Self::SnapshotTooOld),
+            "F0000" => Some(Self::ConfigFileError),
+            "F0001" => Some(Self::LockFileExists),
+            "HV000" => Some(Self::FdwError),
+            "HV001" => Some(Self::FdwOutOfMemory),
+            "HV002" => Some(Self::FdwDynamicParameterValueNeeded),
+            "HV004" => Some(Self::FdwInvalidDataType),
+            "HV005" => Some(Self::FdwColumnNameNotFound),
+            "HV006" => Some(Self::FdwInvalidDataTypeDescriptors),
+            "HV007" => Some(Self::FdwInvalidColumnName),
+            "HV008" => Some(Self::FdwInvalidColumnNumber),
+            "HV009" => Some(Self::FdwInvalidUseOfNullPointer),
+            "HV00A" => Some(Self::FdwInvalidStringFormat),
+            "HV00B" => Some(Self::FdwInvalidHandle),
+            "HV00C" => Some(Self::FdwInvalidOptionIndex),
+            "HV00D" => Some(Self::FdwInvalidOptionName),
+            "HV00J" => Some(Self::FdwOptionNameNotFound),
+            "HV00K" => Some(Self::FdwReplyHandle),
+            "HV00L" => Some(Self::FdwUnableToCreateExecution),
+            "HV00M" => Some(Self::FdwUnableToCreateReply),
+            "HV00N" => Some(Self::FdwUnableToEstablishConnection),
+            "HV00P" => Some(Self::FdwNoSchemas),
+            "HV00Q" => Some(Self::FdwSchemaNotFound),
+            "HV00R" => Some(Self::FdwTableNotFound),
+            "HV010" => Some(Self::FdwFunctionSequenceError),
+            "HV014" => Some(Self::FdwTooManyHandles),
+            "HV021" => Some(Self::FdwInconsistentDescriptorInformation),
+            "HV024" => Some(Self::FdwInvalidAttributeValue),
+            "HV090" => Some(Self::FdwInvalidStringLengthOrBufferLength),
+            "HV091" => Some(Self::FdwInvalidDescriptorFieldIdentifier),
+            "P0000" => Some(Self::PlpgsqlError),
+            "P0001" => Some(Self::RaiseException),
+            "P0002" => Some(Self::NoDataFound),
+            "P0003" => Some(Self::TooManyRows),
+            "P0004" => Some(Self::AssertFailure),
+            "XX000" => Some(Self::InternalError),
+            "XX001" => Some(Self::DataCorrupted),
+            "XX002" => Some(Self::IndexCorrupted),
+            _ => None,
+        }
+    }
+
+    /// Returns the `State` of this error code.
+    pub fn state(&self) -> &'static State {
+        match self {
+            Self::SuccessfulCompletion => &SUCCESSFUL_COMPLETION,
+            Self::Warning => &WARNING,
+            Self::WarningNullValueEliminatedInSetFunction => &WARNING_NULL_VALUE_ELIMINATED_IN_SET_FUNCTION,
+            Self::WarningStringDataRightTruncation => &WARNING_STRING_DATA_RIGHT_TRUNCATION,
+            Self::WarningPrivilegeNotRevoked => &WARNING_PRIVILEGE_NOT_REVOKED,
+            Self::WarningPrivilegeNotGranted => &WARNING_PRIVILEGE_NOT_GRANTED,
+            Self::WarningImplicitZeroBitPadding => &WARNING_IMPLICIT_ZERO_BIT_PADDING,
+            Self::WarningDynamicResultSetsReturned => &WARNING_DYNAMIC_RESULT_SETS_RETURNED,
+            Self::WarningDeprecatedFeature => &WARNING_DEPRECATED_FEATURE,
+            Self::NoData => &NO_DATA,
+            Self::NoAdditionalDynamicResultSetsReturned => &NO_ADDITIONAL_DYNAMIC_RESULT_SETS_RETURNED,
+            Self::SqlStatementNotYetComplete => &SQL_STATEMENT_NOT_YET_COMPLETE,
+            Self::ConnectionException => &CONNECTION_EXCEPTION,
+            Self::SqlclientUnableToEstablishSqlconnection => &SQLCLIENT_UNABLE_TO_ESTABLISH_SQLCONNECTION,
+            Self::ConnectionDoesNotExist => &CONNECTION_DOES_NOT_EXIST,
+            Self::SqlserverRejectedEstablishmentOfSqlconnection => &SQLSERVER_REJECTED_ESTABLISHMENT_OF_SQLCONNECTION,
+            Self::ConnectionFailure => &CONNECTION_FAILURE,
+            Self::TransactionResolutionUnknown => &TRANSACTION_RESOLUTION_UNKNOWN,
+            Self::ProtocolViolation => &PROTOCOL_VIOLATION,
+            Self::TriggeredActionException => &TRIGGERED_ACTION_EXCEPTION,
+            Self::FeatureNotSupported => &FEATURE_NOT_SUPPORTED,
+            Self::InvalidTransactionInitiation => &INVALID_TRANSACTION_INITIATION,
+            Self::LocatorException => &LOCATOR_EXCEPTION,
+            Self::LEInvalidSpecification => &L_E_INVALID_SPECIFICATION,
+            Self::InvalidGrantor => &INVALID_GRANTOR,
+            Self::InvalidGrantOperation => &INVALID_GRANT_OPERATION,
+            Self::InvalidRoleSpecification => &INVALID_ROLE_SPECIFICATION,
+            Self::DiagnosticsException => &DIAGNOSTICS_EXCEPTION,
+            Self::StackedDiagnosticsAccessedWithoutActiveHandler => &STACKED_DIAGNOSTICS_ACCESSED_WITHOUT_ACTIVE_HANDLER,
+            Self::CaseNotFound => &CASE_NOT_FOUND,
+            Self::CardinalityViolation => &CARDINALITY_VIOLATION,
+            Self::DataException => &DATA_EXCEPTION,
+            Self::StringDataRightTruncation => &STRING_DATA_RIGHT_TRUNCATION,
+            Self::NullValueNoIndicatorParameter => &NULL_VALUE_NO_INDICATOR_PARAMETER,
+            Self::NumericValueOutOfRange => &NUMERIC_VALUE_OUT_OF_RANGE,
+            Self::NullValueNotAllowed => &NULL_VALUE_NOT_ALLOWED,
+            Self::ErrorInAssignment => &ERROR_IN_ASSIGNMENT,
+            Self::InvalidDatetimeFormat => &INVALID_DATETIME_FORMAT,
+            Self::DatetimeValueOutOfRange => &DATETIME_VALUE_OUT_OF_RANGE,
+            Self::InvalidTimeZoneDisplacementValue => &INVALID_TIME_ZONE_DISPLACEMENT_VALUE,
+            Self::EscapeCharacterConflict => &ESCAPE_CHARACTER_CONFLICT,
+            Self::InvalidUseOfEscapeCharacter => &INVALID_USE_OF_ESCAPE_CHARACTER,
+            Self::InvalidEscapeOctet => &INVALID_ESCAPE_OCTET,
+            Self::ZeroLengthCharacterString => &ZERO_LENGTH_CHARACTER_STRING,
+            Self::MostSpecificTypeMismatch => &MOST_SPECIFIC_TYPE_MISMATCH,
+            Self::SequenceGeneratorLimitExceeded => &SEQUENCE_GENERATOR_LIMIT_EXCEEDED,
+            Self::NotAnXmlDocument => &NOT_AN_XML_DOCUMENT,
+            Self::InvalidXmlDocument => &INVALID_XML_DOCUMENT,
+            Self::InvalidXmlContent => &INVALID_XML_CONTENT,
+            Self::InvalidXmlComment => &INVALID_XML_COMMENT,
+            Self::InvalidXmlProcessingInstruction => &INVALID_XML_PROCESSING_INSTRUCTION,
+            Self::InvalidIndicatorParameterValue => &INVALID_INDICATOR_PARAMETER_VALUE,
+            Self::SubstringError => &SUBSTRING_ERROR,
+            Self::DivisionByZero => &DIVISION_BY_ZERO,
+            Self::InvalidPrecedingOrFollowingSize => &INVALID_PRECEDING_OR_FOLLOWING_SIZE,
+            Self::InvalidArgumentForNtile => &INVALID_ARGUMENT_FOR_NTILE,
+            Self::IntervalFieldOverflow => &INTERVAL_FIELD_OVERFLOW,
+            Self::InvalidArgumentForNthValue => &INVALID_ARGUMENT_FOR_NTH_VALUE,
+            Self::InvalidCharacterValueForCast => &INVALID_CHARACTER_VALUE_FOR_CAST,
+            Self::InvalidEscapeCharacter => &INVALID_ESCAPE_CHARACTER,
+            Self::InvalidRegularExpression => &INVALID_REGULAR_EXPRESSION,
+            Self::InvalidArgumentForLog => &INVALID_ARGUMENT_FOR_LOG,
+            Self::InvalidArgumentForPowerFunction => &INVALID_ARGUMENT_FOR_POWER_FUNCTION,
+            Self::InvalidArgumentForWidthBucketFunction => &INVALID_ARGUMENT_FOR_WIDTH_BUCKET_FUNCTION,
+            Self::InvalidRowCountInLimitClause => &INVALID_ROW_COUNT_IN_LIMIT_CLAUSE,
+            Self::InvalidRowCountInResultOffsetClause => &INVALID_ROW_COUNT_IN_RESULT_OFFSET_CLAUSE,
+            Self::CharacterNotInRepertoire => &CHARACTER_NOT_IN_REPERTOIRE,
+            Self::IndicatorOverflow => &INDICATOR_OVERFLOW,
+            Self::InvalidParameterValue => &INVALID_PARAMETER_VALUE,
+            Self::UnterminatedCString => &UNTERMINATED_C_STRING,
+            Self::InvalidEscapeSequence => &INVALID_ESCAPE_SEQUENCE,
+            Self::StringDataLengthMismatch => &STRING_DATA_LENGTH_MISMATCH,
+            Self::TrimError => &TRIM_ERROR,
+            Self::ArraySubscriptError => &ARRAY_SUBSCRIPT_ERROR,
+            Self::InvalidTablesampleRepeat => &INVALID_TABLESAMPLE_REPEAT,
+            Self::InvalidTablesampleArgument => &INVALID_TABLESAMPLE_ARGUMENT,
+            Self::DuplicateJsonObjectKeyValue => &DUPLICATE_JSON_OBJECT_KEY_VALUE,
+            Self::InvalidArgumentForSqlJsonDatetimeFunction => &INVALID_ARGUMENT_FOR_SQL_JSON_DATETIME_FUNCTION,
+            Self::InvalidJsonText => &INVALID_JSON_TEXT,
+            Self::InvalidSqlJsonSubscript => &INVALID_SQL_JSON_SUBSCRIPT,
+            Self::MoreThanOneSqlJsonItem => &MORE_THAN_ONE_SQL_JSON_ITEM,
+            Self::NoSqlJsonItem => &NO_SQL_JSON_ITEM,
+            Self::NonNumericSqlJsonItem => &NON_NUMERIC_SQL_JSON_ITEM,
+            Self::NonUniqueKeysInAJsonObject => &NON_UNIQUE_KEYS_IN_A_JSON_OBJECT,
+            Self::SingletonSqlJsonItemRequired => &SINGLETON_SQL_JSON_ITEM_REQUIRED,
+            Self::SqlJsonArrayNotFound => &SQL_JSON_ARRAY_NOT_FOUND,
+            Self::SqlJsonMemberNotFound => &SQL_JSON_MEMBER_NOT_FOUND,
+            Self::SqlJsonNumberNotFound => &SQL_JSON_NUMBER_NOT_FOUND,
+            Self::SqlJsonObjectNotFound => &SQL_JSON_OBJECT_NOT_FOUND,
+            Self::TooManyJsonArrayElements => &TOO_MANY_JSON_ARRAY_ELEMENTS,
+            Self::TooManyJsonObjectMembers => &TOO_MANY_JSON_OBJECT_MEMBERS,
+            Self::SqlJsonScalarRequired => &SQL_JSON_SCALAR_REQUIRED,
+            Self::FloatingPointException => &FLOATING_POINT_EXCEPTION,
+            Self::InvalidTextRepresentation => &INVALID_TEXT_REPRESENTATION,
+            Self::InvalidBinaryRepresentation => &INVALID_BINARY_REPRESENTATION,
+            Self::BadCopyFileFormat => &BAD_COPY_FILE_FORMAT,
+            Self::UntranslatableCharacter => &UNTRANSLATABLE_CHARACTER,
+            Self::NonstandardUseOfEscapeCharacter => &NONSTANDARD_USE_OF_ESCAPE_CHARACTER,
+            Self::IntegrityConstraintViolation => &INTEGRITY_CONSTRAINT_VIOLATION,
+            Self::RestrictViolation => &RESTRICT_VIOLATION,
+            Self::NotNullViolation => &NOT_NULL_VIOLATION,
+            Self::ForeignKeyViolation => &FOREIGN_KEY_VIOLATION,
+            Self::UniqueViolation => &UNIQUE_VIOLATION,
+            Self::CheckViolation => &CHECK_VIOLATION,
+            Self::ExclusionViolation => &EXCLUSION_VIOLATION,
+            Self::InvalidCursorState => &INVALID_CURSOR_STATE,
+            Self::InvalidTransactionState => &INVALID_TRANSACTION_STATE,
+            Self::ActiveSqlTransaction => &ACTIVE_SQL_TRANSACTION,
+            Self::BranchTransactionAlreadyActive => &BRANCH_TRANSACTION_ALREADY_ACTIVE,
+            Self::InappropriateAccessModeForBranchTransaction => &INAPPROPRIATE_ACCESS_MODE_FOR_BRANCH_TRANSACTION,
+            Self::InappropriateIsolationLevelForBranchTransaction => &INAPPROPRIATE_ISOLATION_LEVEL_FOR_BRANCH_TRANSACTION,
+            Self::NoActiveSqlTransactionForBranchTransaction => &NO_ACTIVE_SQL_TRANSACTION_FOR_BRANCH_TRANSACTION,
+            Self::ReadOnlySqlTransaction => &READ_ONLY_SQL_TRANSACTION,
+            Self::SchemaAndDataStatementMixingNotSupported => &SCHEMA_AND_DATA_STATEMENT_MIXING_NOT_SUPPORTED,
+            Self::HeldCursorRequiresSameIsolationLevel => &HELD_CURSOR_REQUIRES_SAME_ISOLATION_LEVEL,
+            Self::NoActiveSqlTransaction => &NO_ACTIVE_SQL_TRANSACTION,
+            Self::InFailedSqlTransaction => &IN_FAILED_SQL_TRANSACTION,
+            Self::IdleInTransactionSessionTimeout => &IDLE_IN_TRANSACTION_SESSION_TIMEOUT,
+            Self::UndefinedPstatement => &UNDEFINED_PSTATEMENT,
+            Self::TriggeredDataChangeViolation => &TRIGGERED_DATA_CHANGE_VIOLATION,
+            Self::InvalidAuthorizationSpecification => &INVALID_AUTHORIZATION_SPECIFICATION,
+            Self::InvalidPassword => &INVALID_PASSWORD,
+            Self::DependentPrivilegeDescriptorsStillExist => &DEPENDENT_PRIVILEGE_DESCRIPTORS_STILL_EXIST,
+            Self::DependentObjectsStillExist => &DEPENDENT_OBJECTS_STILL_EXIST,
+            Self::InvalidTransactionTermination => &INVALID_TRANSACTION_TERMINATION,
+            Self::SqlRoutineException => &SQL_ROUTINE_EXCEPTION,
+            Self::SREModifyingSqlDataNotPermitted => &S_R_E_MODIFYING_SQL_DATA_NOT_PERMITTED,
+            Self::SREProhibitedSqlStatementAttempted => &S_R_E_PROHIBITED_SQL_STATEMENT_ATTEMPTED,
+            Self::SREReadingSqlDataNotPermitted => &S_R_E_READING_SQL_DATA_NOT_PERMITTED,
+            Self::SREFunctionExecutedNoReturnStatement => &S_R_E_FUNCTION_EXECUTED_NO_RETURN_STATEMENT,
+            Self::UndefinedCursor => &UNDEFINED_CURSOR,
+            Self::ExternalRoutineException => &EXTERNAL_ROUTINE_EXCEPTION,
+            Self::EREContainingSqlNotPermitted => &E_R_E_CONTAINING_SQL_NOT_PERMITTED,
+            Self::EREModifyingSqlDataNotPermitted => &E_R_E_MODIFYING_SQL_DATA_NOT_PERMITTED,
+            Self::EREProhibitedSqlStatementAttempted => &E_R_E_PROHIBITED_SQL_STATEMENT_ATTEMPTED,
+            Self::EREReadingSqlDataNotPermitted => &E_R_E_READING_SQL_DATA_NOT_PERMITTED,
+            Self::ExternalRoutineInvocationException => &EXTERNAL_ROUTINE_INVOCATION_EXCEPTION,
+            Self::ERIEInvalidSqlstateReturned => &E_R_I_E_INVALID_SQLSTATE_RETURNED,
+            Self::ERIENullValueNotAllowed => &E_R_I_E_NULL_VALUE_NOT_ALLOWED,
+            Self::ERIETriggerProtocolViolated => &E_R_I_E_TRIGGER_PROTOCOL_VIOLATED,
+            Self::ERIESrfProtocolViolated => &E_R_I_E_SRF_PROTOCOL_VIOLATED,
+            Self::ERIEEventTriggerProtocolViolated => &E_R_I_E_EVENT_TRIGGER_PROTOCOL_VIOLATED,
+            Self::SavepointException => &SAVEPOINT_EXCEPTION,
+            Self::SEInvalidSpecification => &S_E_INVALID_SPECIFICATION,
+            Self::UndefinedDatabase => &UNDEFINED_DATABASE,
+            Self::UndefinedSchema => &UNDEFINED_SCHEMA,
+            Self::TransactionRollback => &TRANSACTION_ROLLBACK,
+            Self::TRSerializationFailure => &T_R_SERIALIZATION_FAILURE,
+            Self::TRIntegrityConstraintViolation => &T_R_INTEGRITY_CONSTRAINT_VIOLATION,
+            Self::TRStatementCompletionUnknown => &T_R_STATEMENT_COMPLETION_UNKNOWN,
+            Self::TRDeadlockDetected => &T_R_DEADLOCK_DETECTED,
+            Self::SyntaxErrorOrAccessRuleViolation => &SYNTAX_ERROR_OR_ACCESS_RULE_VIOLATION,
+            Self::InsufficientPrivilege => &INSUFFICIENT_PRIVILEGE,
+            Self::SyntaxError => &SYNTAX_ERROR,
+            Self::InvalidName => &INVALID_NAME,
+            Self::InvalidColumnDefinition => &INVALID_COLUMN_DEFINITION,
+            Self::NameTooLong => &NAME_TOO_LONG,
+            Self::DuplicateColumn => &DUPLICATE_COLUMN,
+            Self::AmbiguousColumn => &AMBIGUOUS_COLUMN,
+            Self::UndefinedColumn => &UNDEFINED_COLUMN,
+            Self::UndefinedObject => &UNDEFINED_OBJECT,
+            Self::DuplicateObject => &DUPLICATE_OBJECT,
+            Self::DuplicateAlias => &DUPLICATE_ALIAS,
+            Self::DuplicateFunction => &DUPLICATE_FUNCTION,
+            Self::AmbiguousFunction => &AMBIGUOUS_FUNCTION,
+            Self::GroupingError => &GROUPING_ERROR,
+            Self::DatatypeMismatch => &DATATYPE_MISMATCH,
+            Self::WrongObjectType => &WRONG_OBJECT_TYPE,
+            Self::InvalidForeignKey => &INVALID_FOREIGN_KEY,
+            Self::CannotCoerce => &CANNOT_COERCE,
+            Self::UndefinedFunction => &UNDEFINED_FUNCTION,
+            Self::GeneratedAlways => &GENERATED_ALWAYS,
+            Self::ReservedName => &RESERVED_NAME,
+            Self::UndefinedTable => &UNDEFINED_TABLE,
+            Self::UndefinedParameter => &UNDEFINED_PARAMETER,
+            Self::DuplicateCursor => &DUPLICATE_CURSOR,
+            Self::DuplicateDatabase => &DUPLICATE_DATABASE,
+            Self::DuplicatePstatement => &DUPLICATE_PSTATEMENT,
+            Self::DuplicateSchema => &DUPLICATE_SCHEMA,
+            Self::DuplicateTable => &DUPLICATE_TABLE,
+            Self::AmbiguousParameter => &AMBIGUOUS_PARAMETER,
+            Self::AmbiguousAlias => &AMBIGUOUS_ALIAS,
+            Self::InvalidColumnReference => &INVALID_COLUMN_REFERENCE,
+            Self::InvalidCursorDefinition => &INVALID_CURSOR_DEFINITION,
+            Self::InvalidDatabaseDefinition => &INVALID_DATABASE_DEFINITION,
+            Self::InvalidFunctionDefinition => &INVALID_FUNCTION_DEFINITION,
+            Self::InvalidPstatementDefinition => &INVALID_PSTATEMENT_DEFINITION,
+            Self::InvalidSchemaDefinition => &INVALID_SCHEMA_DEFINITION,
+            Self::InvalidTableDefinition => &INVALID_TABLE_DEFINITION,
+            Self::InvalidObjectDefinition => &INVALID_OBJECT_DEFINITION,
+            Self::IndeterminateDatatype => &INDETERMINATE_DATATYPE,
+            Self::InvalidRecursion => &INVALID_RECURSION,
+            Self::WindowingError => &WINDOWING_ERROR,
+            Self::CollationMismatch => &COLLATION_MISMATCH,
+            Self::IndeterminateCollation => &INDETERMINATE_COLLATION,
+            Self::WithCheckOptionViolation => &WITH_CHECK_OPTION_VIOLATION,
+            Self::InsufficientResources => &INSUFFICIENT_RESOURCES,
+            Self::DiskFull => &DISK_FULL,
+            Self::OutOfMemory => &OUT_OF_MEMORY,
+            Self::TooManyConnections => &TOO_MANY_CONNECTIONS,
+            Self::ConfigurationLimitExceeded => &CONFIGURATION_LIMIT_EXCEEDED,
+            Self::ProgramLimitExceeded => &PROGRAM_LIMIT_EXCEEDED,
+            Self::StatementTooComplex => &STATEMENT_TOO_COMPLEX,
+            Self::TooManyColumns => &TOO_MANY_COLUMNS,
+            Self::TooManyArguments => &TOO_MANY_ARGUMENTS,
+            Self::ObjectNotInPrerequisiteState => &OBJECT_NOT_IN_PREREQUISITE_STATE,
+            Self::ObjectInUse => &OBJECT_IN_USE,
+            Self::CantChangeRuntimeParam => &CANT_CHANGE_RUNTIME_PARAM,
+            Self::LockNotAvailable => &LOCK_NOT_AVAILABLE,
+            Self::UnsafeNewEnumValueUsage => &UNSAFE_NEW_ENUM_VALUE_USAGE,
+            Self::OperatorIntervention => &OPERATOR_INTERVENTION,
+            Self::QueryCanceled => &QUERY_CANCELED,
+            Self::AdminShutdown => &ADMIN_SHUTDOWN,
+            Self::CrashShutdown => &CRASH_SHUTDOWN,
+            Self::CannotConnectNow => &CANNOT_CONNECT_NOW,
+            Self::DatabaseDropped => &DATABASE_DROPPED,
+            Self::IdleSessionTimeout => &IDLE_SESSION_TIMEOUT,
+            Self::SystemError => &SYSTEM_ERROR,
+            Self::IoError => &IO_ERROR,
+            Self::UndefinedFile => &UNDEFINED_FILE,
+            Self::DuplicateFile => &DUPLICATE_FILE,
+            Self::SnapshotTooOld => &SNAPSHOT_TOO_OLD,
+            Self::ConfigFileError => &CONFIG_FILE_ERROR,
+            Self::LockFileExists => &LOCK_FILE_EXISTS,
+            Self::FdwError => &FDW_ERROR,
+            Self::FdwOutOfMemory => &FDW_OUT_OF_MEMORY,
+            Self::FdwDynamicParameterValueNeeded => &FDW_DYNAMIC_PARAMETER_VALUE_NEEDED,
+            Self::FdwInvalidDataType => &FDW_INVALID_DATA_TYPE,
+            Self::FdwColumnNameNotFound => &FDW_COLUMN_NAME_NOT_FOUND,
+            Self::FdwInvalidDataTypeDescriptors => &FDW_INVALID_DATA_TYPE_DESCRIPTORS,
+            Self::FdwInvalidColumnName => &FDW_INVALID_COLUMN_NAME,
+            Self::FdwInvalidColumnNumber => &FDW_INVALID_COLUMN_NUMBER,
+            Self::FdwInvalidUseOfNullPointer => &FDW_INVALID_USE_OF_NULL_POINTER,
+            Self::FdwInvalidStringFormat => &FDW_INVALID_STRING_FORMAT,
+            Self::FdwInvalidHandle => &FDW_INVALID_HANDLE,
+            Self::FdwInvalidOptionIndex => &FDW_INVALID_OPTION_INDEX,
+            Self::FdwInvalidOptionName => &FDW_INVALID_OPTION_NAME,
+            Self::FdwOptionNameNotFound => &FDW_OPTION_NAME_NOT_FOUND,
+            Self::FdwReplyHandle => &FDW_REPLY_HANDLE,
+            Self::FdwUnableToCreateExecution => &FDW_UNABLE_TO_CREATE_EXECUTION,
+            Self::FdwUnableToCreateReply => &FDW_UNABLE_TO_CREATE_REPLY,
+            Self::FdwUnableToEstablishConnection => &FDW_UNABLE_TO_ESTABLISH_CONNECTION,
+            Self::FdwNoSchemas => &FDW_NO_SCHEMAS,
+            Self::FdwSchemaNotFound => &FDW_SCHEMA_NOT_FOUND,
+            Self::FdwTableNotFound => &FDW_TABLE_NOT_FOUND,
+            Self::FdwFunctionSequenceError => &FDW_FUNCTION_SEQUENCE_ERROR,
+            Self::FdwTooManyHandles => &FDW_TOO_MANY_HANDLES,
+            Self::FdwInconsistentDescriptorInformation => &FDW_INCONSISTENT_DESCRIPTOR_INFORMATION,
+            Self::FdwInvalidAttributeValue => &FDW_INVALID_ATTRIBUTE_VALUE,
+            Self::FdwInvalidStringLengthOrBufferLength => &FDW_INVALID_STRING_LENGTH_OR_BUFFER_LENGTH,
+            Self::FdwInvalidDescriptorFieldIdentifier => &FDW_INVALID_DESCRIPTOR_FIELD_IDENTIFIER,
+            Self::PlpgsqlError => &PLPGSQL_ERROR,
+            Self::RaiseException => &RAISE_EXCEPTION,
+            Self::NoDataFound => &NO_DATA_FOUND,
+            Self::TooManyRows => &TOO_MANY_ROWS,
+            Self::AssertFailure => &ASSERT_FAILURE,
+            Self::InternalError => &INTERNAL_ERROR,
+            Self::DataCorrupted => &DATA_CORRUPTED,
+            Self::IndexCorrupted => &INDEX_CORRUPTED,
         }
     }
 }
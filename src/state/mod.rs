@@ -14,4 +14,95 @@ pub struct State {
     pub message: Option<&'static str>,
 }
 
+impl State {
+    /**
+     * Creates a `State` from its error code.
+     *
+     * An unknown code, for example sent by a newer server, falls back to the generic state of its
+     * class, or to `UNKNOWN` if the class is unknown too.
+     */
+    pub fn from_code(s: &str) -> State {
+        Self::try_from_code(s)
+            .or_else(|| {
+                s.get(..2)
+                    .and_then(|x| Self::try_from_code(&format!("{x}000")))
+            })
+            .unwrap_or(UNKNOWN)
+    }
+
+    /**
+     * Returns the `SqlState` of this state.
+     */
+    pub fn sql_state(&self) -> Option<SqlState> {
+        SqlState::from_code(self.code)
+    }
+
+    /**
+     * The transaction could not be serialized, see [Serialization Failure
+     * Handling](https://www.postgresql.org/docs/current/mvcc-serialization-failure-handling.html).
+     */
+    pub fn is_serialization_failure(&self) -> bool {
+        self.code == T_R_SERIALIZATION_FAILURE.code
+    }
+
+    /**
+     * The transaction was aborted to resolve a deadlock.
+     */
+    pub fn is_deadlock_detected(&self) -> bool {
+        self.code == T_R_DEADLOCK_DETECTED.code
+    }
+
+    /**
+     * The transaction failed because of concurrent transactions and may succeed if retried.
+     */
+    pub fn is_retryable(&self) -> bool {
+        self.is_serialization_failure() || self.is_deadlock_detected()
+    }
+}
+
+/**
+ * State of a code whose class is unknown to this crate, it belongs to no class.
+ */
+pub const UNKNOWN: State = State {
+    code: "",
+    name: "UNKNOWN",
+    kind: Kind::Error,
+    message: None,
+};
+
 include!("gen.rs");
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn from_code() {
+        assert_eq!(super::State::from_code("23505"), super::UNIQUE_VIOLATION);
+        assert_eq!(
+            super::State::from_code("23P99"),
+            super::INTEGRITY_CONSTRAINT_VIOLATION
+        );
+        assert_eq!(super::State::from_code("ZZ999"), super::UNKNOWN);
+        assert!(!super::State::from_code("ZZ999").is_internal_error());
+        assert_eq!(super::State::try_from_code("ZZ999"), None);
+    }
+
+    #[test]
+    fn sql_state() {
+        let state = super::SqlState::from_code("40001").unwrap();
+
+        assert_eq!(state, super::SqlState::TRSerializationFailure);
+        assert_eq!(state.state(), &super::T_R_SERIALIZATION_FAILURE);
+        assert_eq!(super::T_R_SERIALIZATION_FAILURE.sql_state(), Some(state));
+        assert_eq!(super::SqlState::from_code("ZZ999"), None);
+    }
+
+    #[test]
+    fn class() {
+        assert!(super::UNIQUE_VIOLATION.is_integrity_constraint_violation());
+        assert!(super::CONNECTION_FAILURE.is_connection_exception());
+        assert!(super::T_R_SERIALIZATION_FAILURE.is_transaction_rollback());
+        assert!(super::T_R_SERIALIZATION_FAILURE.is_retryable());
+        assert!(super::T_R_DEADLOCK_DETECTED.is_retryable());
+        assert!(!super::UNIQUE_VIOLATION.is_retryable());
+    }
+}
@@ -38,6 +38,14 @@ pub enum Status {
     #[cfg(feature = "v14")]
     #[cfg_attr(docsrs, doc(cfg(feature = "v14")))]
     PipelineAborted,
+
+    /**
+     * The `libpq::PQResult` contains several result tuples from the current command. This status
+     * occurs only when chunked mode has been selected for the query.
+     */
+    #[cfg(feature = "v17")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "v17")))]
+    TuplesChunk,
 }
 
 #[doc(hidden)]
@@ -60,6 +68,9 @@ impl From<pq_sys::ExecStatusType> for Status {
             #[cfg(feature = "v14")]
             #[cfg_attr(docsrs, doc(cfg(feature = "v14")))]
             pq_sys::ExecStatusType::PGRES_PIPELINE_ABORTED => Self::PipelineAborted,
+            #[cfg(feature = "v17")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "v17")))]
+            pq_sys::ExecStatusType::PGRES_TUPLES_CHUNK => Self::TuplesChunk,
             #[allow(unreachable_patterns)]
             _ => unreachable!(),
         }
@@ -95,6 +106,9 @@ impl From<&Status> for pq_sys::ExecStatusType {
             #[cfg(feature = "v14")]
             #[cfg_attr(docsrs, doc(cfg(feature = "v14")))]
             Status::PipelineAborted => pq_sys::ExecStatusType::PGRES_PIPELINE_ABORTED,
+            #[cfg(feature = "v17")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "v17")))]
+            Status::TuplesChunk => pq_sys::ExecStatusType::PGRES_TUPLES_CHUNK,
             #[allow(unreachable_patterns)]
             _ => unreachable!(),
         }
@@ -0,0 +1,336 @@
+mod options;
+mod retry;
+mod status;
+
+pub use options::*;
+pub use retry::*;
+pub use status::*;
+
+/**
+ * A transaction guard, see `libpq::Connection::transaction`.
+ *
+ * The transaction is rolled back when the guard is dropped without calling
+ * `Transaction::commit`.
+ *
+ * # Examples
+ *
+ * ```
+ * # let dsn = std::env::var("PQ_DSN").unwrap_or_else(|_| "host=localhost".to_string());
+ * let conn = libpq::Connection::new(&dsn).unwrap();
+ *
+ * let mut tx = conn.transaction().unwrap();
+ * tx.try_exec("CREATE TEMPORARY TABLE tx (id int)").unwrap();
+ *
+ * let savepoint = tx.savepoint("insert").unwrap();
+ * savepoint.try_exec("INSERT INTO tx VALUES (1)").unwrap();
+ * savepoint.rollback().unwrap();
+ *
+ * tx.commit().unwrap();
+ * ```
+ */
+#[derive(Debug)]
+pub struct Transaction<'conn> {
+    conn: &'conn crate::Connection,
+    savepoint: Option<String>,
+    done: bool,
+}
+
+impl<'conn> Transaction<'conn> {
+    pub(crate) fn begin(
+        conn: &'conn crate::Connection,
+        options: &Options,
+    ) -> crate::errors::Result<Self> {
+        let status = conn.transaction_status();
+
+        if status != Status::Idle {
+            return Err(crate::errors::Error::TransactionStatus(status));
+        }
+
+        conn.try_exec(&options.to_string())?;
+
+        Ok(Self {
+            conn,
+            savepoint: None,
+            done: false,
+        })
+    }
+
+    /**
+     * Creates a savepoint, released by `Transaction::commit` of the returned guard or rolled
+     * back when it is dropped.
+     */
+    pub fn savepoint(&mut self, name: &str) -> crate::errors::Result<Transaction<'_>> {
+        let name = self.conn.escape_identifier(name)?.to_str()?.to_string();
+
+        self.conn.try_exec(&format!("SAVEPOINT {name}"))?;
+
+        Ok(Transaction {
+            conn: self.conn,
+            savepoint: Some(name),
+            done: false,
+        })
+    }
+
+    /**
+     * Commits the transaction, or releases the savepoint.
+     *
+     * Returns an error, and rolls back, if the transaction is in a failed state.
+     */
+    pub fn commit(mut self) -> crate::errors::Result {
+        self.done = true;
+
+        let status = self.conn.transaction_status();
+
+        if status != Status::InTrans {
+            self.finish_rollback()?;
+
+            return Err(crate::errors::Error::TransactionStatus(status));
+        }
+
+        match &self.savepoint {
+            Some(name) => self.conn.try_exec(&format!("RELEASE SAVEPOINT {name}"))?,
+            None => self.conn.try_exec("COMMIT")?,
+        };
+
+        Ok(())
+    }
+
+    /**
+     * Rolls back the transaction, or to the savepoint.
+     */
+    pub fn rollback(mut self) -> crate::errors::Result {
+        self.done = true;
+        self.finish_rollback()
+    }
+
+    /**
+     * Returns the connection of this transaction.
+     */
+    pub fn connection(&self) -> &'conn crate::Connection {
+        self.conn
+    }
+
+    fn finish_rollback(&self) -> crate::errors::Result {
+        match &self.savepoint {
+            Some(name) => {
+                self.conn
+                    .try_exec(&format!("ROLLBACK TO SAVEPOINT {name}"))?;
+                self.conn.try_exec(&format!("RELEASE SAVEPOINT {name}"))?;
+            }
+            None => {
+                self.conn.try_exec("ROLLBACK")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::ops::Deref for Transaction<'_> {
+    type Target = crate::Connection;
+
+    fn deref(&self) -> &Self::Target {
+        self.conn
+    }
+}
+
+impl Drop for Transaction<'_> {
+    fn drop(&mut self) {
+        if !self.done {
+            if let Err(err) = self.finish_rollback() {
+                log::warn!("Unable to rollback transaction: {err}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    fn count(conn: &crate::Connection) -> Option<i64> {
+        conn.try_exec("SELECT count(*) FROM tx")
+            .unwrap()
+            .get(0, 0)
+            .ok()
+    }
+
+    fn new_conn() -> crate::Connection {
+        let conn = crate::test::new_conn();
+        conn.try_exec("CREATE TEMPORARY TABLE tx (id int)").unwrap();
+
+        conn
+    }
+
+    #[test]
+    fn commit() {
+        let conn = new_conn();
+
+        let tx = conn.transaction().unwrap();
+        assert_eq!(
+            conn.transaction_status(),
+            crate::transaction::Status::InTrans
+        );
+        tx.try_exec("INSERT INTO tx VALUES (1)").unwrap();
+        tx.commit().unwrap();
+
+        assert_eq!(conn.transaction_status(), crate::transaction::Status::Idle);
+        assert_eq!(count(&conn), Some(1));
+    }
+
+    #[test]
+    fn rollback_on_drop() {
+        let conn = new_conn();
+
+        {
+            let tx = conn.transaction().unwrap();
+            tx.try_exec("INSERT INTO tx VALUES (1)").unwrap();
+        }
+
+        assert_eq!(conn.transaction_status(), crate::transaction::Status::Idle);
+        assert_eq!(count(&conn), Some(0));
+    }
+
+    #[test]
+    fn in_error() {
+        let conn = new_conn();
+
+        let tx = conn.transaction().unwrap();
+        tx.try_exec("INSERT INTO tx VALUES (1)").unwrap();
+        assert!(tx.try_exec("SELECT 1/0").is_err());
+        assert_eq!(
+            tx.commit(),
+            Err(crate::errors::Error::TransactionStatus(
+                crate::transaction::Status::InError
+            ))
+        );
+
+        assert_eq!(conn.transaction_status(), crate::transaction::Status::Idle);
+        assert_eq!(count(&conn), Some(0));
+    }
+
+    #[test]
+    fn nested() {
+        let conn = new_conn();
+        let tx = conn.transaction().unwrap();
+
+        assert!(conn.transaction().is_err());
+        tx.rollback().unwrap();
+    }
+
+    #[test]
+    fn savepoint() {
+        let conn = new_conn();
+
+        let mut tx = conn.transaction().unwrap();
+        tx.try_exec("INSERT INTO tx VALUES (1)").unwrap();
+
+        {
+            let mut savepoint = tx.savepoint("first").unwrap();
+            savepoint.try_exec("INSERT INTO tx VALUES (2)").unwrap();
+
+            let nested = savepoint.savepoint("second").unwrap();
+            nested.try_exec("INSERT INTO tx VALUES (3)").unwrap();
+            assert!(nested.try_exec("SELECT 1/0").is_err());
+            assert!(nested.commit().is_err());
+
+            savepoint.commit().unwrap();
+        }
+
+        {
+            let savepoint = tx.savepoint("third").unwrap();
+            savepoint.try_exec("INSERT INTO tx VALUES (4)").unwrap();
+        }
+
+        tx.commit().unwrap();
+        assert_eq!(count(&conn), Some(2));
+    }
+
+    #[test]
+    fn options() {
+        let conn = new_conn();
+        let options = crate::transaction::Options::new()
+            .isolation_level(crate::transaction::IsolationLevel::Serializable)
+            .read_only(true)
+            .deferrable(true);
+
+        let tx = conn.transaction_with(&options).unwrap();
+        let result = tx.try_exec("SHOW transaction_isolation").unwrap();
+        assert_eq!(result.get::<&str>(0, 0), Ok("serializable"));
+        let result = tx.try_exec("SHOW transaction_read_only").unwrap();
+        assert_eq!(result.get::<&str>(0, 0), Ok("on"));
+        tx.rollback().unwrap();
+    }
+
+    fn fail(tx: &crate::Connection, state: &str) -> crate::errors::Result {
+        tx.try_exec(&format!(
+            "DO $$ BEGIN RAISE EXCEPTION 'failure' USING ERRCODE = '{state}'; END $$"
+        ))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn retry() {
+        let conn = new_conn();
+        let retry = crate::transaction::Retry::new().delay(std::time::Duration::ZERO);
+        let mut attempts = 0;
+
+        let result = conn.transaction_with_retry(&retry, |tx| {
+            attempts += 1;
+            tx.try_exec("INSERT INTO tx VALUES (1)")?;
+
+            match attempts {
+                1 => fail(tx, "40001"),
+                2 => fail(tx, "40P01"),
+                _ => Ok(()),
+            }
+        });
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(attempts, 3);
+        assert_eq!(count(&conn), Some(1));
+    }
+
+    #[test]
+    fn retry_exhausted() {
+        let conn = new_conn();
+        let retry = crate::transaction::Retry::new()
+            .attempts(2)
+            .delay(std::time::Duration::ZERO);
+        let mut attempts = 0;
+
+        let error = conn
+            .transaction_with_retry(&retry, |tx| {
+                attempts += 1;
+                fail(tx, "40001")
+            })
+            .unwrap_err();
+
+        assert_eq!(attempts, 2);
+        assert!(matches!(
+            error,
+            crate::errors::Error::Retry { attempts: 2, .. }
+        ));
+        assert_eq!(
+            error.state(),
+            Some(&crate::state::T_R_SERIALIZATION_FAILURE)
+        );
+        assert_eq!(conn.transaction_status(), crate::transaction::Status::Idle);
+    }
+
+    #[test]
+    fn retry_not_retryable() {
+        let conn = new_conn();
+        let retry = crate::transaction::Retry::new();
+        let mut attempts = 0;
+
+        let error = conn
+            .transaction_with_retry(&retry, |tx| {
+                attempts += 1;
+                fail(tx, "23505")
+            })
+            .unwrap_err();
+
+        assert_eq!(attempts, 1);
+        assert_eq!(error.state(), Some(&crate::state::UNIQUE_VIOLATION));
+    }
+}
@@ -0,0 +1,108 @@
+/**
+ * See [Transaction Isolation](https://www.postgresql.org/docs/current/transaction-iso.html).
+ */
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl std::fmt::Display for IsolationLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::ReadUncommitted => "READ UNCOMMITTED",
+            Self::ReadCommitted => "READ COMMITTED",
+            Self::RepeatableRead => "REPEATABLE READ",
+            Self::Serializable => "SERIALIZABLE",
+        };
+
+        f.write_str(s)
+    }
+}
+
+/**
+ * Transaction modes, unset modes use the session defaults.
+ *
+ * See [BEGIN](https://www.postgresql.org/docs/current/sql-begin.html).
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Options {
+    isolation_level: Option<IsolationLevel>,
+    read_only: Option<bool>,
+    deferrable: Option<bool>,
+}
+
+impl Options {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /**
+     * Sets the transaction isolation level.
+     */
+    pub fn isolation_level(mut self, isolation_level: IsolationLevel) -> Self {
+        self.isolation_level = Some(isolation_level);
+        self
+    }
+
+    /**
+     * Sets the transaction access mode, `READ ONLY` or `READ WRITE`.
+     */
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = Some(read_only);
+        self
+    }
+
+    /**
+     * Sets the `DEFERRABLE` mode, only used by serializable read only transactions.
+     */
+    pub fn deferrable(mut self, deferrable: bool) -> Self {
+        self.deferrable = Some(deferrable);
+        self
+    }
+}
+
+impl std::fmt::Display for Options {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut modes = Vec::new();
+
+        if let Some(isolation_level) = self.isolation_level {
+            modes.push(format!("ISOLATION LEVEL {isolation_level}"));
+        }
+
+        match self.read_only {
+            Some(true) => modes.push("READ ONLY".to_string()),
+            Some(false) => modes.push("READ WRITE".to_string()),
+            None => (),
+        }
+
+        match self.deferrable {
+            Some(true) => modes.push("DEFERRABLE".to_string()),
+            Some(false) => modes.push("NOT DEFERRABLE".to_string()),
+            None => (),
+        }
+
+        if modes.is_empty() {
+            f.write_str("BEGIN")
+        } else {
+            write!(f, "BEGIN {}", modes.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn to_string() {
+        assert_eq!(super::Options::new().to_string(), "BEGIN");
+        assert_eq!(
+            super::Options::new()
+                .isolation_level(super::IsolationLevel::RepeatableRead)
+                .read_only(false)
+                .to_string(),
+            "BEGIN ISOLATION LEVEL REPEATABLE READ, READ WRITE"
+        );
+    }
+}
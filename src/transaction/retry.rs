@@ -0,0 +1,113 @@
+/**
+ * Retry policy of `libpq::Connection::transaction_with_retry`.
+ *
+ * The delay between attempts starts at `delay` and doubles after each failure, up to
+ * `max_delay`.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Retry {
+    options: super::Options,
+    attempts: u32,
+    delay: std::time::Duration,
+    max_delay: std::time::Duration,
+}
+
+impl Default for Retry {
+    fn default() -> Self {
+        Self {
+            options: super::Options::default(),
+            attempts: 5,
+            delay: std::time::Duration::from_millis(10),
+            max_delay: std::time::Duration::from_secs(1),
+        }
+    }
+}
+
+impl Retry {
+    /**
+     * Creates the default policy: 5 attempts, with a delay from 10ms up to 1s.
+     */
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /**
+     * Sets the transaction modes.
+     */
+    pub fn options(mut self, options: super::Options) -> Self {
+        self.options = options;
+        self
+    }
+
+    /**
+     * Sets the maximum number of attempts, including the first one.
+     */
+    pub fn attempts(mut self, attempts: u32) -> Self {
+        self.attempts = attempts.max(1);
+        self
+    }
+
+    /**
+     * Sets the delay before the first retry.
+     */
+    pub fn delay(mut self, delay: std::time::Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /**
+     * Sets the maximum delay between two attempts.
+     */
+    pub fn max_delay(mut self, max_delay: std::time::Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub(crate) fn run<T, F>(&self, conn: &crate::Connection, mut f: F) -> crate::errors::Result<T>
+    where
+        F: FnMut(&mut super::Transaction<'_>) -> crate::errors::Result<T>,
+    {
+        let mut delay = self.delay;
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+
+            let error = match Self::attempt(conn, &self.options, &mut f) {
+                Ok(value) => return Ok(value),
+                Err(error) => error,
+            };
+
+            if !error.state().is_some_and(crate::State::is_retryable) {
+                return Err(error);
+            }
+
+            if attempt >= self.attempts {
+                return Err(crate::errors::Error::Retry {
+                    attempts: attempt,
+                    error: Box::new(error),
+                });
+            }
+
+            log::debug!("Transaction failed on attempt {attempt}, retrying in {delay:?}: {error}");
+
+            std::thread::sleep(delay);
+            delay = delay.saturating_mul(2).min(self.max_delay);
+        }
+    }
+
+    fn attempt<T, F>(
+        conn: &crate::Connection,
+        options: &super::Options,
+        f: &mut F,
+    ) -> crate::errors::Result<T>
+    where
+        F: FnMut(&mut super::Transaction<'_>) -> crate::errors::Result<T>,
+    {
+        let mut tx = conn.transaction_with(options)?;
+        let value = f(&mut tx)?;
+        tx.commit()?;
+
+        Ok(value)
+    }
+}
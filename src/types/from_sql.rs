@@ -0,0 +1,290 @@
+/**
+ * A trait for types that can be created from a PostgreSQL value.
+ *
+ * Values are received in text or binary format, see [`crate::Format`]. A `NULL` value is
+ * represented by `raw` being `None`, only `Option<T>` accepts it.
+ */
+pub trait FromSql<'a>: Sized {
+    /**
+     * Creates a new value of this type from a buffer of data of the specified PostgreSQL type
+     * and format.
+     */
+    fn from_sql(
+        ty: &crate::Type,
+        format: crate::Format,
+        raw: Option<&'a [u8]>,
+    ) -> crate::errors::Result<Self>;
+
+    /**
+     * Returns `true` if this type can be created from the specified PostgreSQL type.
+     */
+    fn accepts(ty: &crate::Type) -> bool;
+}
+
+fn check<'a, T: FromSql<'a>>(
+    ty: &crate::Type,
+    raw: Option<&'a [u8]>,
+) -> crate::errors::Result<&'a [u8]> {
+    if !T::accepts(ty) {
+        return Err(crate::errors::Error::WrongType {
+            ty: ty.name.to_string(),
+            rust: std::any::type_name::<T>(),
+        });
+    }
+
+    raw.ok_or(crate::errors::Error::UnexpectedNull)
+}
+
+fn binary<const N: usize>(raw: &[u8]) -> crate::errors::Result<[u8; N]> {
+    raw.try_into().map_err(|_| {
+        crate::errors::Error::Conversion(format!(
+            "invalid buffer size, expected {N} bytes, got {}",
+            raw.len()
+        ))
+    })
+}
+
+impl<'a, T: FromSql<'a>> FromSql<'a> for Option<T> {
+    fn from_sql(
+        ty: &crate::Type,
+        format: crate::Format,
+        raw: Option<&'a [u8]>,
+    ) -> crate::errors::Result<Self> {
+        match raw {
+            Some(_) => T::from_sql(ty, format, raw).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn accepts(ty: &crate::Type) -> bool {
+        T::accepts(ty)
+    }
+}
+
+impl<'a> FromSql<'a> for bool {
+    fn from_sql(
+        ty: &crate::Type,
+        format: crate::Format,
+        raw: Option<&'a [u8]>,
+    ) -> crate::errors::Result<Self> {
+        let raw = check::<Self>(ty, raw)?;
+
+        match (format, raw) {
+            (crate::Format::Text, b"t") | (crate::Format::Binary, [1]) => Ok(true),
+            (crate::Format::Text, b"f") | (crate::Format::Binary, [0]) => Ok(false),
+            _ => Err(crate::errors::Error::Conversion(format!(
+                "invalid boolean {raw:?}"
+            ))),
+        }
+    }
+
+    fn accepts(ty: &crate::Type) -> bool {
+        ty.oid == crate::types::BOOL.oid
+    }
+}
+
+macro_rules! from_sql_number {
+    ($t:ty, $($ty:ident),+) => {
+        impl<'a> FromSql<'a> for $t {
+            fn from_sql(
+                ty: &crate::Type,
+                format: crate::Format,
+                raw: Option<&'a [u8]>,
+            ) -> crate::errors::Result<Self> {
+                let raw = check::<Self>(ty, raw)?;
+
+                match format {
+                    crate::Format::Text => Ok(std::str::from_utf8(raw)?.parse()?),
+                    crate::Format::Binary => Ok(<$t>::from_be_bytes(binary(raw)?)),
+                }
+            }
+
+            fn accepts(ty: &crate::Type) -> bool {
+                $(ty.oid == crate::types::$ty.oid)||+
+            }
+        }
+    };
+}
+
+from_sql_number!(i16, INT2);
+from_sql_number!(i32, INT4);
+from_sql_number!(i64, INT8);
+from_sql_number!(f32, FLOAT4);
+from_sql_number!(f64, FLOAT8);
+from_sql_number!(u32, OID);
+
+impl<'a> FromSql<'a> for &'a str {
+    fn from_sql(
+        ty: &crate::Type,
+        _: crate::Format,
+        raw: Option<&'a [u8]>,
+    ) -> crate::errors::Result<Self> {
+        let raw = check::<Self>(ty, raw)?;
+
+        Ok(std::str::from_utf8(raw)?)
+    }
+
+    fn accepts(ty: &crate::Type) -> bool {
+        [
+            crate::types::TEXT.oid,
+            crate::types::VARCHAR.oid,
+            crate::types::BPCHAR.oid,
+            crate::types::NAME.oid,
+            crate::types::UNKNOWN.oid,
+        ]
+        .contains(&ty.oid)
+    }
+}
+
+impl<'a> FromSql<'a> for String {
+    fn from_sql(
+        ty: &crate::Type,
+        format: crate::Format,
+        raw: Option<&'a [u8]>,
+    ) -> crate::errors::Result<Self> {
+        <&str>::from_sql(ty, format, raw).map(String::from)
+    }
+
+    fn accepts(ty: &crate::Type) -> bool {
+        <&str>::accepts(ty)
+    }
+}
+
+impl<'a> FromSql<'a> for &'a [u8] {
+    fn from_sql(
+        ty: &crate::Type,
+        format: crate::Format,
+        raw: Option<&'a [u8]>,
+    ) -> crate::errors::Result<Self> {
+        let raw = check::<Self>(ty, raw)?;
+
+        match format {
+            crate::Format::Binary => Ok(raw),
+            crate::Format::Text => Err(crate::errors::Error::Conversion(
+                "cannot borrow a bytea in text format, use Vec<u8> instead".to_string(),
+            )),
+        }
+    }
+
+    fn accepts(ty: &crate::Type) -> bool {
+        ty.oid == crate::types::BYTEA.oid
+    }
+}
+
+impl<'a> FromSql<'a> for Vec<u8> {
+    fn from_sql(
+        ty: &crate::Type,
+        format: crate::Format,
+        raw: Option<&'a [u8]>,
+    ) -> crate::errors::Result<Self> {
+        let raw = check::<Self>(ty, raw)?;
+
+        match format {
+            crate::Format::Binary => Ok(raw.to_vec()),
+            crate::Format::Text => match raw.strip_prefix(b"\\x") {
+                Some(hex) => hex
+                    .chunks(2)
+                    .map(|x| {
+                        std::str::from_utf8(x)
+                            .ok()
+                            .and_then(|x| u8::from_str_radix(x, 16).ok())
+                            .ok_or_else(|| {
+                                crate::errors::Error::Conversion(format!("invalid bytea {raw:?}"))
+                            })
+                    })
+                    .collect(),
+                None if raw.is_empty() => Ok(Vec::new()),
+                None => {
+                    let mut text = raw.to_vec();
+                    text.push(b'\0');
+
+                    Ok(crate::escape::unescape_bytea(&text)?.to_vec())
+                }
+            },
+        }
+    }
+
+    fn accepts(ty: &crate::Type) -> bool {
+        <&[u8]>::accepts(ty)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FromSql;
+
+    #[test]
+    fn bool() {
+        let ty = crate::types::BOOL;
+
+        assert_eq!(
+            bool::from_sql(&ty, crate::Format::Text, Some(b"t")),
+            Ok(true)
+        );
+        assert_eq!(
+            bool::from_sql(&ty, crate::Format::Binary, Some(&[0])),
+            Ok(false)
+        );
+        assert!(bool::from_sql(&ty, crate::Format::Text, Some(b"true")).is_err());
+    }
+
+    #[test]
+    fn number() {
+        assert_eq!(
+            i32::from_sql(&crate::types::INT4, crate::Format::Text, Some(b"-42")),
+            Ok(-42)
+        );
+        assert_eq!(
+            i64::from_sql(
+                &crate::types::INT8,
+                crate::Format::Binary,
+                Some(&[0, 0, 0, 0, 0, 0, 1, 0])
+            ),
+            Ok(256)
+        );
+        assert!(
+            f64::from_sql(&crate::types::FLOAT8, crate::Format::Text, Some(b"NaN"))
+                .unwrap()
+                .is_nan()
+        );
+        assert_eq!(
+            f32::from_sql(
+                &crate::types::FLOAT4,
+                crate::Format::Text,
+                Some(b"-Infinity")
+            ),
+            Ok(f32::NEG_INFINITY)
+        );
+    }
+
+    #[test]
+    fn wrong_type() {
+        assert_eq!(
+            i16::from_sql(&crate::types::INT4, crate::Format::Text, Some(b"1")),
+            Err(crate::errors::Error::WrongType {
+                ty: "int4".to_string(),
+                rust: "i16"
+            })
+        );
+    }
+
+    #[test]
+    fn null() {
+        assert_eq!(
+            String::from_sql(&crate::types::TEXT, crate::Format::Text, None),
+            Err(crate::errors::Error::UnexpectedNull)
+        );
+        assert_eq!(
+            Option::<String>::from_sql(&crate::types::TEXT, crate::Format::Text, None),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn bytea() {
+        assert_eq!(
+            Vec::<u8>::from_sql(&crate::types::BYTEA, crate::Format::Text, Some(b"\\x00ff")),
+            Ok(vec![0, 255])
+        );
+    }
+}
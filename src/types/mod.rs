@@ -1,3 +1,9 @@
+mod from_sql;
+mod to_sql;
+
+pub use from_sql::*;
+pub use to_sql::*;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Kind {
     Array(crate::Oid),
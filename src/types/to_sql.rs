@@ -0,0 +1,294 @@
+/**
+ * A trait for types that can be sent to PostgreSQL as a query parameter.
+ */
+pub trait ToSql {
+    /**
+     * Returns the PostgreSQL type of this value.
+     */
+    fn ty(&self) -> crate::Type;
+
+    /**
+     * Returns the format used to send this value.
+     */
+    fn format(&self) -> crate::Format {
+        crate::Format::Binary
+    }
+
+    /**
+     * Converts the value of `self` into the specified format, `None` means `NULL`.
+     *
+     * The text format must not contain the terminating null byte.
+     */
+    fn to_sql(&self, format: crate::Format) -> crate::errors::Result<Option<Vec<u8>>>;
+}
+
+/**
+ * The PostgreSQL type of a rust type, known without a value.
+ *
+ * It gives the type of the `NULL` sent for `None`.
+ */
+pub trait SqlType {
+    /**
+     * Returns the PostgreSQL type of this rust type.
+     */
+    fn sql_type() -> crate::Type;
+}
+
+impl<T: SqlType + ?Sized> SqlType for &T {
+    fn sql_type() -> crate::Type {
+        T::sql_type()
+    }
+}
+
+impl<T: ToSql + ?Sized> ToSql for &T {
+    fn ty(&self) -> crate::Type {
+        (*self).ty()
+    }
+
+    fn format(&self) -> crate::Format {
+        (*self).format()
+    }
+
+    fn to_sql(&self, format: crate::Format) -> crate::errors::Result<Option<Vec<u8>>> {
+        (*self).to_sql(format)
+    }
+}
+
+/**
+ * The type of a `NULL` value is `T::sql_type()`.
+ */
+impl<T: ToSql + SqlType> ToSql for Option<T> {
+    fn ty(&self) -> crate::Type {
+        match self {
+            Some(value) => value.ty(),
+            None => T::sql_type(),
+        }
+    }
+
+    fn format(&self) -> crate::Format {
+        match self {
+            Some(value) => value.format(),
+            None => crate::Format::Text,
+        }
+    }
+
+    fn to_sql(&self, format: crate::Format) -> crate::errors::Result<Option<Vec<u8>>> {
+        match self {
+            Some(value) => value.to_sql(format),
+            None => Ok(None),
+        }
+    }
+}
+
+impl SqlType for bool {
+    fn sql_type() -> crate::Type {
+        crate::types::BOOL
+    }
+}
+
+impl ToSql for bool {
+    fn ty(&self) -> crate::Type {
+        Self::sql_type()
+    }
+
+    fn to_sql(&self, format: crate::Format) -> crate::errors::Result<Option<Vec<u8>>> {
+        let value = match format {
+            crate::Format::Text => if *self { b"t" } else { b"f" }.to_vec(),
+            crate::Format::Binary => vec![*self as u8],
+        };
+
+        Ok(Some(value))
+    }
+}
+
+macro_rules! to_sql_number {
+    ($t:ty, $ty:ident) => {
+        impl SqlType for $t {
+            fn sql_type() -> crate::Type {
+                crate::types::$ty
+            }
+        }
+
+        impl ToSql for $t {
+            fn ty(&self) -> crate::Type {
+                Self::sql_type()
+            }
+
+            fn to_sql(&self, format: crate::Format) -> crate::errors::Result<Option<Vec<u8>>> {
+                let value = match format {
+                    crate::Format::Text => self.to_string().into_bytes(),
+                    crate::Format::Binary => self.to_be_bytes().to_vec(),
+                };
+
+                Ok(Some(value))
+            }
+        }
+    };
+}
+
+to_sql_number!(i16, INT2);
+to_sql_number!(i32, INT4);
+to_sql_number!(i64, INT8);
+to_sql_number!(u32, OID);
+
+macro_rules! to_sql_float {
+    ($t:ty, $ty:ident) => {
+        impl SqlType for $t {
+            fn sql_type() -> crate::Type {
+                crate::types::$ty
+            }
+        }
+
+        impl ToSql for $t {
+            fn ty(&self) -> crate::Type {
+                Self::sql_type()
+            }
+
+            fn to_sql(&self, format: crate::Format) -> crate::errors::Result<Option<Vec<u8>>> {
+                let value = match format {
+                    crate::Format::Text if self.is_nan() => b"NaN".to_vec(),
+                    crate::Format::Text if self.is_infinite() && self.is_sign_positive() => {
+                        b"Infinity".to_vec()
+                    }
+                    crate::Format::Text if self.is_infinite() => b"-Infinity".to_vec(),
+                    crate::Format::Text => self.to_string().into_bytes(),
+                    crate::Format::Binary => self.to_be_bytes().to_vec(),
+                };
+
+                Ok(Some(value))
+            }
+        }
+    };
+}
+
+to_sql_float!(f32, FLOAT4);
+to_sql_float!(f64, FLOAT8);
+
+impl SqlType for str {
+    fn sql_type() -> crate::Type {
+        crate::types::TEXT
+    }
+}
+
+impl ToSql for str {
+    fn ty(&self) -> crate::Type {
+        Self::sql_type()
+    }
+
+    fn format(&self) -> crate::Format {
+        crate::Format::Text
+    }
+
+    fn to_sql(&self, _: crate::Format) -> crate::errors::Result<Option<Vec<u8>>> {
+        if self.contains('\0') {
+            return Err(crate::errors::Error::Conversion(
+                "text value contains a null byte".to_string(),
+            ));
+        }
+
+        Ok(Some(self.as_bytes().to_vec()))
+    }
+}
+
+impl SqlType for String {
+    fn sql_type() -> crate::Type {
+        <str>::sql_type()
+    }
+}
+
+impl ToSql for String {
+    fn ty(&self) -> crate::Type {
+        self.as_str().ty()
+    }
+
+    fn format(&self) -> crate::Format {
+        self.as_str().format()
+    }
+
+    fn to_sql(&self, format: crate::Format) -> crate::errors::Result<Option<Vec<u8>>> {
+        self.as_str().to_sql(format)
+    }
+}
+
+impl SqlType for [u8] {
+    fn sql_type() -> crate::Type {
+        crate::types::BYTEA
+    }
+}
+
+impl ToSql for [u8] {
+    fn ty(&self) -> crate::Type {
+        Self::sql_type()
+    }
+
+    fn to_sql(&self, format: crate::Format) -> crate::errors::Result<Option<Vec<u8>>> {
+        let value = match format {
+            crate::Format::Text => {
+                let mut hex = b"\\x".to_vec();
+
+                for byte in self {
+                    hex.extend(format!("{byte:02x}").as_bytes());
+                }
+
+                hex
+            }
+            crate::Format::Binary => self.to_vec(),
+        };
+
+        Ok(Some(value))
+    }
+}
+
+impl SqlType for Vec<u8> {
+    fn sql_type() -> crate::Type {
+        <[u8]>::sql_type()
+    }
+}
+
+impl ToSql for Vec<u8> {
+    fn ty(&self) -> crate::Type {
+        self.as_slice().ty()
+    }
+
+    fn format(&self) -> crate::Format {
+        self.as_slice().format()
+    }
+
+    fn to_sql(&self, format: crate::Format) -> crate::errors::Result<Option<Vec<u8>>> {
+        self.as_slice().to_sql(format)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ToSql;
+
+    #[test]
+    fn number() {
+        assert_eq!(1_i32.to_sql(crate::Format::Text), Ok(Some(b"1".to_vec())));
+        assert_eq!(1_i16.to_sql(crate::Format::Binary), Ok(Some(vec![0, 1])));
+        assert_eq!(
+            f64::NEG_INFINITY.to_sql(crate::Format::Text),
+            Ok(Some(b"-Infinity".to_vec()))
+        );
+    }
+
+    #[test]
+    fn null() {
+        let value: Option<i64> = None;
+
+        assert_eq!(value.ty(), crate::types::INT8);
+        assert_eq!(value.to_sql(crate::Format::Binary), Ok(None));
+
+        assert_eq!(None::<&[u8]>.ty(), crate::types::BYTEA);
+        assert_eq!(None::<&str>.ty(), crate::types::TEXT);
+    }
+
+    #[test]
+    fn bytea() {
+        assert_eq!(
+            b"\0\xff"[..].to_sql(crate::Format::Text),
+            Ok(Some(b"\\x00ff".to_vec()))
+        );
+    }
+}